@@ -0,0 +1,345 @@
+//! Benchmarking for `pallet_halom_oracle`.
+//!
+//! `impl_benchmark_test_suite!` below runs every benchmark as a regular test
+//! against `crate::mock::Test`, so these weights are only ever exercised once
+//! the mock actually compiles against the pallet's current `Config`.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as HalomOracle;
+use codec::Encode;
+use frame_benchmarking::v2::*;
+use frame_support::{pallet_prelude::*, traits::Currency};
+use frame_system::{offchain::AppCrypto, RawOrigin};
+use sp_runtime::{traits::{Hash as HashT, Saturating}, RuntimeAppPublic};
+use sp_std::prelude::*;
+
+/// Mint a `T::Public` backed by a freshly generated, keystore-resident
+/// `T::AuthorityId` key, so a signed-payload extrinsic has something to
+/// report as its signer without needing a real offchain worker.
+fn authority_public<T: Config<I>, I: 'static>() -> T::Public {
+    let public = <T::AuthorityId as AppCrypto<T::Public, T::Signature>>::RuntimeAppPublic::generate_pair(None);
+    <T::AuthorityId as AppCrypto<T::Public, T::Signature>>::GenericPublic::from(public).into()
+}
+
+/// Sign `msg` with the keystore key backing `public`, as the offchain worker
+/// would via `Signer::any_account()`.
+fn authority_sign<T: Config<I>, I: 'static>(public: &T::Public, msg: &[u8]) -> T::Signature {
+    let generic_public = <T::AuthorityId as AppCrypto<T::Public, T::Signature>>::GenericPublic::try_from(public.clone())
+        .unwrap_or_else(|_| panic!("benchmark-generated public key round-trips through GenericPublic"));
+    let app_public: <T::AuthorityId as AppCrypto<T::Public, T::Signature>>::RuntimeAppPublic = generic_public.into();
+    let app_signature = app_public.sign(&msg).expect("benchmark key is resident in the local keystore");
+    let generic_signature: <T::AuthorityId as AppCrypto<T::Public, T::Signature>>::GenericSignature =
+        app_signature.into();
+    generic_signature.into()
+}
+
+fn seed_allowed_source<T: Config<I>, I: 'static>(source: Vec<u8>) {
+    let sources = BoundedVec::try_from(vec![source]).expect("single source fits AllowedSources' bound");
+    AllowedSources::<T, I>::put(sources);
+}
+
+fn seed_members<T: Config<I>, I: 'static>(members: Vec<T::AccountId>) {
+    let bounded = BoundedVec::try_from(members).expect("benchmark member count stays within MaxCouncilMembers");
+    Members::<T, I>::put(bounded);
+}
+
+#[benchmarks(instance, where T::RuntimeCall: From<frame_system::Call<T>>)]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn submit_hoi() {
+        let origin = T::OracleUpdateOrigin::try_successful_origin().unwrap();
+        frame_system::Pallet::<T>::set_block_number(HalomOracle::<T, I>::update_interval());
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, 105);
+
+        assert_eq!(CurrentHOI::<T, I>::get(), 105);
+    }
+
+    #[benchmark]
+    fn update_parameters() {
+        let origin = T::GovernanceOrigin::try_successful_origin().unwrap();
+        let new_interval = T::MinUpdateInterval::get();
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, new_interval, 1);
+
+        assert_eq!(MinSources::<T, I>::get(), 1);
+    }
+
+    #[benchmark]
+    fn set_source_value_field() {
+        let origin = T::GovernanceOrigin::try_successful_origin().unwrap();
+        let field = BoundedVec::try_from(b"value".to_vec()).unwrap();
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, field);
+    }
+
+    #[benchmark]
+    fn set_consensus_outlier_factor() {
+        let origin = T::GovernanceOrigin::try_successful_origin().unwrap();
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, 3);
+
+        assert_eq!(ConsensusOutlierFactor::<T, I>::get(), Some(3));
+    }
+
+    #[benchmark]
+    fn set_prime_member() {
+        let origin = T::GovernanceOrigin::try_successful_origin().unwrap();
+        let prime: T::AccountId = account("prime", 0, 0);
+        seed_members::<T, I>(vec![prime.clone()]);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, Some(prime.clone()));
+
+        assert_eq!(PrimeMember::<T, I>::get(), Some(prime));
+    }
+
+    #[benchmark]
+    fn set_source_reputation() {
+        let origin = T::GovernanceOrigin::try_successful_origin().unwrap();
+        let source = b"KSH".to_vec();
+        seed_allowed_source::<T, I>(source.clone());
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, source.clone(), 100);
+
+        assert_eq!(SourceReputation::<T, I>::get(&source), Some(100));
+    }
+
+    #[benchmark]
+    fn set_reputation_step_size() {
+        let origin = T::GovernanceOrigin::try_successful_origin().unwrap();
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, 5);
+
+        assert_eq!(ReputationStep::<T, I>::get(), Some(5));
+    }
+
+    #[benchmark]
+    fn add_source() {
+        let origin = T::GovernanceOrigin::try_successful_origin().unwrap();
+        let source = b"NEW".to_vec();
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, source.clone());
+
+        assert!(AllowedSources::<T, I>::get().iter().any(|s| s == &source));
+    }
+
+    #[benchmark]
+    fn remove_source() {
+        let origin = T::GovernanceOrigin::try_successful_origin().unwrap();
+        let source = b"KSH".to_vec();
+        seed_allowed_source::<T, I>(source.clone());
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, source.clone());
+
+        assert!(!AllowedSources::<T, I>::get().iter().any(|s| s == &source));
+    }
+
+    #[benchmark]
+    fn submit_source_value() {
+        let origin = T::OracleUpdateOrigin::try_successful_origin().unwrap();
+        let source = b"KSH".to_vec();
+        seed_allowed_source::<T, I>(source.clone());
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, source.clone(), 520);
+
+        assert_eq!(SourceValues::<T, I>::get(&source).0, 520);
+    }
+
+    #[benchmark]
+    fn submit_signed_source_value() {
+        let source = b"KSH".to_vec();
+        seed_allowed_source::<T, I>(source.clone());
+        let public = authority_public::<T, I>();
+        let payload = HOIPayload { source: source.clone(), hoi_value: 520, public: public.clone() };
+        let signature = authority_sign::<T, I>(&public, &payload.encode());
+
+        #[extrinsic_call]
+        _(RawOrigin::None, payload, signature);
+
+        assert_eq!(SourceValues::<T, I>::get(&source).0, 520);
+    }
+
+    #[benchmark]
+    fn submit_observation() {
+        let source = b"KSH".to_vec();
+        seed_allowed_source::<T, I>(source.clone());
+        let public = authority_public::<T, I>();
+        let payload = SourceObservationPayload {
+            source_id: source.clone(),
+            value: 520,
+            block_number: frame_system::Pallet::<T>::block_number(),
+            public: public.clone(),
+        };
+        let signature = authority_sign::<T, I>(&public, &payload.encode());
+
+        #[extrinsic_call]
+        _(RawOrigin::None, payload, signature);
+
+        assert_eq!(Observations::<T, I>::get(&source).len(), 1);
+    }
+
+    #[benchmark]
+    fn propose() {
+        let proposer: T::AccountId = account("proposer", 0, 0);
+        T::Currency::make_free_balance_be(&proposer, T::CouncilBond::get());
+        seed_members::<T, I>(vec![proposer.clone()]);
+        let call: Box<<T as Config<I>>::RuntimeCall> =
+            Box::new(frame_system::Call::<T>::remark { remark: vec![] }.into());
+        let voting_duration = T::MinVotingDuration::get();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(proposer), call, voting_duration);
+
+        assert_eq!(Proposals::<T, I>::iter().count(), 1);
+    }
+
+    #[benchmark]
+    fn enact_proposal() {
+        let proposer: T::AccountId = account("proposer", 0, 0);
+        let call: Box<<T as Config<I>>::RuntimeCall> =
+            Box::new(frame_system::Call::<T>::remark { remark: vec![] }.into());
+        let call_hash = T::Hashing::hash_of(&*call);
+        let proposal = Proposal {
+            proposer,
+            call_hash,
+            call_len: call.encoded_size() as u32,
+            votes_for: Default::default(),
+            votes_against: Default::default(),
+            end_block: frame_system::Pallet::<T>::block_number(),
+            status: ProposalStatus::Approved,
+        };
+        let proposal_hash = T::Hashing::hash_of(&proposal);
+        Proposals::<T, I>::insert(proposal_hash, proposal);
+        let caller: T::AccountId = whitelisted_caller();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), proposal_hash, call);
+
+        assert!(Proposals::<T, I>::get(proposal_hash).is_none());
+    }
+
+    /// `v` worst-cases the number of members already seated, which bounds
+    /// both the `Members` read used for quorum and the proposal's own
+    /// bounded vote lists that `should_resolve_proposal` tallies over.
+    /// Capped at a literal 100 since `T::MaxCouncilMembers` is a runtime
+    /// `Get` and component ranges must be compile-time constants.
+    #[benchmark]
+    fn vote_on_proposal(v: Linear<1, 100>) {
+        let v = v.min(T::MaxCouncilMembers::get().saturating_sub(1));
+        let mut members = Vec::new();
+        for i in 0..v {
+            members.push(account::<T::AccountId>("member", i, 0));
+        }
+        let voter: T::AccountId = account("member", v, 0);
+        members.push(voter.clone());
+        seed_members::<T, I>(members.clone());
+
+        let proposer = members[0].clone();
+        let call: Box<<T as Config<I>>::RuntimeCall> =
+            Box::new(frame_system::Call::<T>::remark { remark: vec![] }.into());
+        let call_hash = T::Hashing::hash_of(&*call);
+        let now = frame_system::Pallet::<T>::block_number();
+        let votes_for = BoundedVec::try_from(
+            members[..v as usize].iter().map(|who| (who.clone(), Conviction::Locked1x, now)).collect::<Vec<_>>(),
+        )
+        .expect("v is bounded by MaxCouncilMembers");
+        let proposal = Proposal {
+            proposer,
+            call_hash,
+            call_len: call.encoded_size() as u32,
+            votes_for,
+            votes_against: Default::default(),
+            end_block: now.saturating_add(T::MinVotingDuration::get()),
+            status: ProposalStatus::Active,
+        };
+        let proposal_hash = T::Hashing::hash_of(&proposal);
+        Proposals::<T, I>::insert(proposal_hash, proposal);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(voter), proposal_hash, true, Conviction::Locked1x);
+    }
+
+    #[benchmark]
+    fn post_council_bond() {
+        let who: T::AccountId = account("member", 0, 0);
+        seed_members::<T, I>(vec![who.clone()]);
+        T::Currency::make_free_balance_be(&who, T::CouncilBond::get());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(who.clone()));
+
+        assert!(CouncilBonds::<T, I>::contains_key(&who));
+    }
+
+    #[benchmark]
+    fn withdraw_council_bond() {
+        let who: T::AccountId = account("member", 0, 0);
+        seed_members::<T, I>(vec![who.clone()]);
+        T::Currency::make_free_balance_be(&who, T::CouncilBond::get());
+        HalomOracle::<T, I>::post_council_bond(RawOrigin::Signed(who.clone()).into()).unwrap();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(who.clone()));
+
+        assert!(!CouncilBonds::<T, I>::contains_key(&who));
+    }
+
+    #[benchmark]
+    fn submit_heartbeat() {
+        let who: T::AccountId = account("member", 0, 0);
+        seed_members::<T, I>(vec![who.clone()]);
+        T::Currency::make_free_balance_be(&who, T::CouncilBond::get());
+        HalomOracle::<T, I>::post_council_bond(RawOrigin::Signed(who.clone()).into()).unwrap();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(who));
+    }
+
+    #[benchmark]
+    fn unlock_vote_balance() {
+        let target: T::AccountId = account("voter", 0, 0);
+        let caller: T::AccountId = whitelisted_caller();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), target);
+    }
+
+    #[benchmark]
+    fn add_member() {
+        let origin = T::MembershipOrigin::try_successful_origin().unwrap();
+        let who: T::AccountId = account("member", 0, 0);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, who.clone());
+
+        assert!(Members::<T, I>::get().contains(&who));
+    }
+
+    #[benchmark]
+    fn remove_member() {
+        let origin = T::MembershipOrigin::try_successful_origin().unwrap();
+        let who: T::AccountId = account("member", 0, 0);
+        seed_members::<T, I>(vec![who.clone()]);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, who.clone());
+
+        assert!(!Members::<T, I>::get().contains(&who));
+    }
+
+    impl_benchmark_test_suite!(HalomOracle, crate::mock::new_test_ext(), crate::mock::Test);
+}
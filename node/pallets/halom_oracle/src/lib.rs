@@ -2,37 +2,80 @@
 
 pub use pallet::*;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Signing infrastructure for the offchain worker: a dedicated application key so
+/// oracle observations can be submitted as signed transactions without exposing a
+/// validator's session keys.
+pub mod crypto {
+    use sp_core::crypto::KeyTypeId;
+    use sp_runtime::{
+        app_crypto::{app_crypto, sr25519},
+        MultiSignature, MultiSigner,
+    };
+
+    /// KeyTypeId for the Halom oracle's offchain-worker signing key.
+    pub const HALOM_ORACLE: KeyTypeId = KeyTypeId(*b"hoic");
+
+    app_crypto!(sr25519, HALOM_ORACLE);
+
+    pub struct OracleAuthId;
+
+    impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for OracleAuthId {
+        type RuntimeAppPublic = Public;
+        type GenericSignature = sp_core::sr25519::Signature;
+        type GenericPublic = sp_core::sr25519::Public;
+    }
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use frame_support::{
         pallet_prelude::*,
-        traits::{Get, EnsureOrigin},
-        dispatch::DispatchResult,
+        traits::{
+            ChangeMembers, Currency, Get, EnsureOrigin, InitializeMembers, LockIdentifier,
+            LockableCurrency, ReservableCurrency, WithdrawReasons,
+        },
+        dispatch::{DispatchResult, Dispatchable, GetDispatchInfo},
         Blake2_128Concat,
     };
-    use frame_system::pallet_prelude::*;
+    use frame_system::{
+        pallet_prelude::*,
+        offchain::{
+            AppCrypto, CreateSignedTransaction, SendUnsignedTransaction, SignedPayload, Signer,
+            SigningTypes,
+        },
+    };
     use sp_runtime::{
         offchain::{
             http,
             storage::StorageValueRef,
             Duration,
         },
-        traits::{Zero, Hash as HashT},
-        RuntimeDebug,
+        traits::{Saturating, Zero, One, Hash as HashT},
+        Permill, RuntimeDebug,
     };
     use sp_std::prelude::*;
     use codec::{Decode, Encode};
     use scale_info::TypeInfo;
+    use crate::weights::WeightInfo;
 
-    // Új típusok a governance-hez
-    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
-    pub enum Parameter<BlockNumber> {
-        UpdateInterval(BlockNumber),
-        MinSources(u32),
-        ConsensusThreshold(u32),
-    }
+    pub type BalanceOf<T, I = ()> =
+        <<T as Config<I>>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
-    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    /// Identifies the currency lock `lock_vote` places on a council member's
+    /// balance for the duration of their vote's conviction lock period.
+    const CONVICTION_VOTE_LOCK_ID: LockIdentifier = *b"halomcvl";
+
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
     pub enum ProposalStatus {
         Active,
         Approved,
@@ -40,39 +83,228 @@ pub mod pallet {
         Expired,
     }
 
-    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
-    pub struct Proposal<AccountId, BlockNumber> {
-        proposer: AccountId,
-        parameter: Parameter<BlockNumber>,
-        votes_for: Vec<AccountId>,
-        votes_against: Vec<AccountId>,
-        end_block: BlockNumber,
-        status: ProposalStatus,
+    /// A proposal carries only the hash and encoded length of the call it
+    /// would dispatch, not the call itself (a bounded-preimage approach, so a
+    /// proposal with an arbitrarily large call can't bloat `Proposals`
+    /// storage). The full call must be resupplied to `enact_proposal` once
+    /// voting has approved it; it's checked against `call_hash`/`call_len`
+    /// before being dispatched.
+    ///
+    /// `votes_for`/`votes_against` are bounded by `MaxVotes` (in practice
+    /// `T::MaxCouncilMembers`, since there can never be more votes than
+    /// council members) rather than `Vec`, so the struct satisfies
+    /// `MaxEncodedLen` for PoV-metered parachains. Fields are `pub` so a
+    /// storage migration in another crate can translate the pre-bound
+    /// layout.
+    #[derive(
+        Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen,
+    )]
+    #[scale_info(skip_type_params(MaxVotes))]
+    pub struct Proposal<AccountId, BlockNumber, Hash, MaxVotes: Get<u32>> {
+        pub proposer: AccountId,
+        pub call_hash: Hash,
+        pub call_len: u32,
+        pub votes_for: BoundedVec<(AccountId, Conviction, BlockNumber), MaxVotes>,
+        pub votes_against: BoundedVec<(AccountId, Conviction, BlockNumber), MaxVotes>,
+        pub end_block: BlockNumber,
+        pub status: ProposalStatus,
+    }
+
+    /// Conviction multiplier a voter locks their vote weight behind, following
+    /// the standard doubling lock schedule: higher conviction means a longer
+    /// lock but a proportionally larger vote weight.
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum Conviction {
+        /// 0.1x vote weight (rounds down to 0 for our integer tally), no lock.
+        None,
+        /// 1x vote weight, locked for one conviction period.
+        Locked1x,
+        /// 2x vote weight, locked for two conviction periods.
+        Locked2x,
+        /// 3x vote weight, locked for four conviction periods.
+        Locked3x,
+        /// 4x vote weight, locked for eight conviction periods.
+        Locked4x,
+        /// 5x vote weight, locked for sixteen conviction periods.
+        Locked5x,
+        /// 6x vote weight, locked for thirty-two conviction periods.
+        Locked6x,
     }
 
-    pub trait IsCouncilMember<AccountId> {
-        fn is_council_member(who: &AccountId) -> bool;
+    impl Conviction {
+        /// Integer vote-weight multiplier. `None` rounds 0.1x down to 0: an
+        /// unlocked vote is recorded but carries no weight.
+        fn multiplier(&self) -> u32 {
+            match self {
+                Conviction::None => 0,
+                Conviction::Locked1x => 1,
+                Conviction::Locked2x => 2,
+                Conviction::Locked3x => 3,
+                Conviction::Locked4x => 4,
+                Conviction::Locked5x => 5,
+                Conviction::Locked6x => 6,
+            }
+        }
+
+        /// Number of `ConvictionVoteLockPeriod`s the vote weight is locked for.
+        fn lock_periods(&self) -> u32 {
+            match self {
+                Conviction::None => 0,
+                Conviction::Locked1x => 1,
+                Conviction::Locked2x => 2,
+                Conviction::Locked3x => 4,
+                Conviction::Locked4x => 8,
+                Conviction::Locked5x => 16,
+                Conviction::Locked6x => 32,
+            }
+        }
     }
 
+    /// Signed payload reporting a single source's raw figure, routed straight into
+    /// `submit_source_value`/`try_consensus` rather than the windowed
+    /// `Observations`/`try_settle_window` path.
     #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
     pub struct HOIPayload<Public> {
+        pub source: Vec<u8>,
         pub hoi_value: u32,
         pub public: Public,
     }
 
+    impl<T: SigningTypes> SignedPayload<T> for HOIPayload<T::Public> {
+        fn public(&self) -> T::Public {
+            self.public.clone()
+        }
+    }
+
+    /// Signed payload submitted by an offchain-worker-held oracle key reporting a
+    /// single source's observed figure for the current window.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct SourceObservationPayload<Public, BlockNumber> {
+        pub source_id: Vec<u8>,
+        pub value: u32,
+        pub block_number: BlockNumber,
+        pub public: Public,
+    }
+
+    impl<T: SigningTypes> SignedPayload<T> for SourceObservationPayload<T::Public, BlockNumberFor<T>> {
+        fn public(&self) -> T::Public {
+            self.public.clone()
+        }
+    }
+
+    /// Strategy for turning a window's raw per-source observations into a single
+    /// committed value, rejecting outliers along the way.
+    pub trait Aggregator<AccountId> {
+        /// Aggregate `observations`, returning the committed value and the list of
+        /// accounts whose observation was rejected as an outlier. Returns `None`
+        /// if fewer than `min_sources` observations survive rejection.
+        fn aggregate(observations: &[(AccountId, u32)], min_sources: u32) -> Option<(u32, Vec<AccountId>)>;
+    }
+
+    /// Starting reputation for an allowed source that hasn't yet had its
+    /// weight set or adjusted by a consensus round.
+    const DEFAULT_REPUTATION: u32 = 100;
+
+    /// Median of an already-sorted slice; averages the two middle values for an
+    /// even-length input.
+    fn median_of(sorted: &[u32]) -> u32 {
+        let len = sorted.len();
+        if len == 0 {
+            return 0;
+        }
+        if len % 2 == 1 {
+            sorted[len / 2]
+        } else {
+            let a = sorted[len / 2 - 1] as u64;
+            let b = sorted[len / 2] as u64;
+            ((a + b) / 2) as u32
+        }
+    }
+
+    /// Median-absolute-deviation outlier filter: discards any value whose
+    /// distance from the median exceeds `k * MAD`, where `k` is a plain integer
+    /// multiplier supplied by `K`. When `MAD == 0` (all values clustered), every value
+    /// is kept.
+    pub struct MedianAbsoluteDeviation<K>(PhantomData<K>);
+
+    impl<AccountId: Clone, K: Get<u32>> Aggregator<AccountId> for MedianAbsoluteDeviation<K> {
+        fn aggregate(observations: &[(AccountId, u32)], min_sources: u32) -> Option<(u32, Vec<AccountId>)> {
+            if observations.is_empty() {
+                return None;
+            }
+
+            let mut values: Vec<u32> = observations.iter().map(|(_, v)| *v).collect();
+            values.sort_unstable();
+            let m = median_of(&values);
+
+            let mut deviations: Vec<u32> = values.iter().map(|v| v.abs_diff(m)).collect();
+            deviations.sort_unstable();
+            let mad = median_of(&deviations);
+            let threshold = K::get().saturating_mul(mad);
+
+            let mut survivors: Vec<u32> = Vec::new();
+            let mut rejected: Vec<AccountId> = Vec::new();
+            for (who, value) in observations {
+                if mad == 0 || value.abs_diff(m) <= threshold {
+                    survivors.push(*value);
+                } else {
+                    rejected.push(who.clone());
+                }
+            }
+
+            if (survivors.len() as u32) < min_sources {
+                return None;
+            }
+
+            survivors.sort_unstable();
+            Some((median_of(&survivors), rejected))
+        }
+    }
+
     #[pallet::config]
-    pub trait Config: frame_system::Config {
-        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
-        
+    pub trait Config<I: 'static = ()>: CreateSignedTransaction<Call<Self, I>> + frame_system::Config {
+        type RuntimeEvent: From<Event<Self, I>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Application crypto used to sign offchain-worker observations.
+        type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
+
+        /// Aggregation strategy turning a window's raw observations into a
+        /// committed HOI value, rejecting outliers along the way.
+        type Aggregator: Aggregator<Self::AccountId>;
+
+        /// `k` in the MAD outlier filter: values further than `k * MAD` from the
+        /// median are discarded.
+        #[pallet::constant]
+        type OutlierDeviationFactor: Get<u32>;
+
+        /// Observations older than this many blocks are excluded from
+        /// aggregation entirely, regardless of outlier status.
+        #[pallet::constant]
+        type MaxObservationAge: Get<Self::BlockNumber>;
+
+        /// Whether `submit_observation`/`try_settle_window` is allowed to
+        /// commit a HOI. This pallet carries two independent consensus paths
+        /// (see the note on [`Pallet::try_settle_window`]); a runtime must
+        /// disable this one so that only `submit_source_value`/
+        /// `submit_signed_source_value` (via `try_consensus`) can publish,
+        /// rather than having both race to commit `CurrentHOI` in the same
+        /// block.
+        #[pallet::constant]
+        type ObservationConsensusEnabled: Get<bool>;
+
         /// The origin that is allowed to update HOI
         type OracleUpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
         
         /// The origin that is allowed to update oracle parameters
         type GovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
-        /// Council membership checking
-        type CouncilMembers: IsCouncilMember<Self::AccountId>;
-        
+        /// Origin allowed to add or remove council members, e.g. a
+        /// `pallet_membership` instance or a root/council-majority origin.
+        /// Membership itself lives in this pallet's own `Members` storage,
+        /// reachable by an external provider via `ChangeMembers`/
+        /// `InitializeMembers` as well as by `add_member`/`remove_member`.
+        type MembershipOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
         /// Voting period for proposals
         #[pallet::constant]
         type VotingPeriod: Get<Self::BlockNumber>;
@@ -92,48 +324,272 @@ pub mod pallet {
         /// Required majority percentage for proposal approval (0-100)
         #[pallet::constant]
         type RequiredMajority: Get<u32>;
+
+        /// Currency used for the council liveness bond and for the
+        /// conviction-weighted vote lock.
+        type Currency: ReservableCurrency<Self::AccountId>
+            + LockableCurrency<Self::AccountId, Moment = Self::BlockNumber>;
+
+        /// Deposit a council member reserves to participate in the liveness
+        /// program; slashed (in whole or in part) on sustained inactivity.
+        #[pallet::constant]
+        type CouncilBond: Get<BalanceOf<Self, I>>;
+
+        /// Fraction of `CouncilBond` slashed each time a member crosses
+        /// `MaxMissedHeartbeats`.
+        #[pallet::constant]
+        type LivenessPenalty: Get<Permill>;
+
+        /// Consecutive `VotingPeriod`s a bonded member may go without a heartbeat
+        /// or a vote before being slashed.
+        #[pallet::constant]
+        type MaxMissedHeartbeats: Get<u32>;
+
+        /// Length of one conviction lock period; a vote's `Conviction` locks the
+        /// voter's weight for `lock_periods() * ConvictionVoteLockPeriod` blocks.
+        #[pallet::constant]
+        type ConvictionVoteLockPeriod: Get<Self::BlockNumber>;
+
+        /// Cap on how many due proposals `on_initialize` finally tallies in a
+        /// single block; the rest of that block's bucket carries over to the
+        /// next block rather than blowing out the block's weight.
+        #[pallet::constant]
+        type MaxAgendaItemsPerBlock: Get<u32>;
+
+        /// The runtime call a proposal dispatches once enacted. Only its hash
+        /// and encoded length live in `Proposals` storage; the call itself is
+        /// resupplied to `enact_proposal`.
+        type RuntimeCall: Parameter
+            + Dispatchable<RuntimeOrigin = Self::RuntimeOrigin>
+            + GetDispatchInfo;
+
+        /// Upper bound on a proposed call's encoded length, checked at
+        /// `propose` time so a proposal can't force a huge call to be
+        /// resupplied (and re-hashed) at enactment.
+        #[pallet::constant]
+        type MaxProposalLen: Get<u32>;
+
+        /// Upper bound on a proposed call's dispatch weight, checked at
+        /// `propose` time.
+        #[pallet::constant]
+        type MaxProposalWeight: Get<Weight>;
+
+        /// Minimum turnout, as a fraction of the live `Members` count, below
+        /// which a proposal expires at `end_block` regardless of its
+        /// for/against ratio.
+        #[pallet::constant]
+        type MinQuorum: Get<Permill>;
+
+        /// Cap `SourceReputation` saturates at after repeated in-tolerance
+        /// consensus rounds.
+        #[pallet::constant]
+        type MaxReputation: Get<u32>;
+
+        /// Floor `SourceReputation` decays toward for sources rejected as
+        /// outliers or that stop submitting; never driven to zero so a source
+        /// can still earn its way back.
+        #[pallet::constant]
+        type MinReputation: Get<u32>;
+
+        /// Default per-round reputation reward/decay step, overridable via
+        /// `set_reputation_step_size`.
+        #[pallet::constant]
+        type ReputationStepSize: Get<u32>;
+
+        /// Tolerance band, as a fraction of the agreed consensus value,
+        /// within which a surviving source's value must fall to be rewarded
+        /// rather than left to decay.
+        #[pallet::constant]
+        type ReputationTolerance: Get<Permill>;
+
+        /// A source's last-submitted value is excluded from `try_consensus`
+        /// entirely once it's older than this many blocks, regardless of
+        /// outlier status.
+        #[pallet::constant]
+        type StalenessWindow: Get<Self::BlockNumber>;
+
+        /// Upper bound on the number of votes a single proposal can record on
+        /// either side, used to bound `Proposal` for `MaxEncodedLen`. Should
+        /// be set to (or above) the largest council the runtime expects,
+        /// since a proposal can never receive more votes than there are
+        /// council members.
+        #[pallet::constant]
+        type MaxCouncilMembers: Get<u32>;
+
+        /// Floor on the caller-supplied `voting_duration` passed to
+        /// `propose`, so an urgent proposal can shorten deliberation but
+        /// can't skip it entirely.
+        #[pallet::constant]
+        type MinVotingDuration: Get<Self::BlockNumber>;
+
+        /// Weight functions needed for this pallet's extrinsics.
+        type WeightInfo: WeightInfo;
     }
 
+    /// Current on-chain layout version. Bump alongside a migration in
+    /// `runtime::migrations` whenever a storage item's shape changes.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
     #[pallet::pallet]
-    pub struct Pallet<T>(_);
+    #[pallet::storage_version(STORAGE_VERSION)]
+    pub struct Pallet<T, I = ()>(_);
+
+    /// Per-source metadata backfilled by the v0 -> v1 migration. Kept separate from
+    /// `AllowedSources` (the live allow-list) so existing reads of that list are
+    /// unaffected by the schema change.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+    pub struct SourceInfo<BlockNumber> {
+        /// Decimal precision the source reports its figure in.
+        pub decimals: u8,
+        /// Block of this source's last accepted observation. Only the v0 -> v1
+        /// migration writes it (as zero); no extrinsic of this pallet updates it.
+        pub last_seen: BlockNumber,
+    }
+
+    #[pallet::storage]
+    #[pallet::getter(fn source_metadata)]
+    pub type SourceMetadata<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, Vec<u8>, SourceInfo<T::BlockNumber>, OptionQuery>;
 
     #[pallet::storage]
     #[pallet::getter(fn current_hoi)]
-    pub type CurrentHOI<T> = StorageValue<_, u32, ValueQuery>;
+    pub type CurrentHOI<T, I = ()> = StorageValue<_, u32, ValueQuery>;
 
     #[pallet::storage]
     #[pallet::getter(fn last_update)]
-    pub type LastUpdate<T: Config> = StorageValue<_, T::BlockNumber, ValueQuery>;
+    pub type LastUpdate<T: Config<I>, I: 'static = ()> = StorageValue<_, T::BlockNumber, ValueQuery>;
 
     #[pallet::storage]
     #[pallet::getter(fn update_interval)]
-    pub type UpdateInterval<T: Config> = StorageValue<_, T::BlockNumber, ValueQuery>;
+    pub type UpdateInterval<T: Config<I>, I: 'static = ()> = StorageValue<_, T::BlockNumber, ValueQuery>;
 
     #[pallet::storage]
     #[pallet::getter(fn min_sources)]
-    pub type MinSources<T: Config> = StorageValue<_, u32, ValueQuery>;
+    pub type MinSources<T: Config<I>, I: 'static = ()> = StorageValue<_, u32, ValueQuery>;
 
     #[pallet::storage]
     #[pallet::getter(fn allowed_sources)]
-    pub type AllowedSources<T> = StorageValue<_, BoundedVec<Vec<u8>, ConstU32<10>>, ValueQuery>;
+    pub type AllowedSources<T, I = ()> = StorageValue<_, BoundedVec<Vec<u8>, ConstU32<10>>, ValueQuery>;
 
+    /// Each source's last-submitted raw value, alongside the block it was
+    /// submitted at so `try_consensus` can exclude stale reports.
     #[pallet::storage]
     #[pallet::getter(fn source_values)]
-    pub type SourceValues<T> = StorageMap<_, Blake2_128Concat, Vec<u8>, u32, ValueQuery>;
+    pub type SourceValues<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, Vec<u8>, (u32, T::BlockNumber), ValueQuery>;
 
     #[pallet::storage]
     #[pallet::getter(fn proposals)]
-    pub type Proposals<T: Config> = StorageMap<
+    pub type Proposals<T: Config<I>, I: 'static = ()> = StorageMap<
         _,
         Blake2_128Concat,
         T::Hash,
-        Proposal<T::AccountId, T::BlockNumber>,
+        Proposal<T::AccountId, T::BlockNumber, T::Hash, T::MaxCouncilMembers>,
         OptionQuery
     >;
 
+    /// Per-source observations reported by offchain-worker-held keys during the
+    /// current window, keyed by source id. Each entry also records the block at
+    /// which it was submitted so stale observations can be excluded.
+    #[pallet::storage]
+    #[pallet::getter(fn observations)]
+    pub type Observations<T: Config<I>, I: 'static = ()> = StorageMap<
+        _,
+        Blake2_128Concat,
+        Vec<u8>,
+        BoundedVec<(T::AccountId, u32, T::BlockNumber), ConstU32<32>>,
+        ValueQuery,
+    >;
+
+    /// Block at which the current observation window started; cleared once a new
+    /// HOI value is committed from it.
+    #[pallet::storage]
+    #[pallet::getter(fn observation_window_start)]
+    pub type ObservationWindowStart<T: Config<I>, I: 'static = ()> = StorageValue<_, T::BlockNumber, ValueQuery>;
+
+    /// Council liveness bonds currently reserved, keyed by member. Only members
+    /// with a bond posted are tracked by the heartbeat/slashing subsystem.
+    #[pallet::storage]
+    #[pallet::getter(fn council_bond)]
+    pub type CouncilBonds<T: Config<I>, I: 'static = ()> = StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T, I>, OptionQuery>;
+
+    /// Block at which a bonded member last proved liveness, via either a
+    /// heartbeat or a vote on a proposal.
+    #[pallet::storage]
+    #[pallet::getter(fn last_liveness)]
+    pub type LastLiveness<T: Config<I>, I: 'static = ()> = StorageMap<_, Blake2_128Concat, T::AccountId, T::BlockNumber, ValueQuery>;
+
+    /// Consecutive `VotingPeriod`s a bonded member has been found inactive in.
+    #[pallet::storage]
+    #[pallet::getter(fn missed_heartbeats)]
+    pub type MissedHeartbeats<T: Config<I>, I: 'static = ()> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Block at which the liveness check last ran, so `on_initialize` only acts
+    /// once per `VotingPeriod`.
+    #[pallet::storage]
+    #[pallet::getter(fn last_liveness_check)]
+    pub type LastLivenessCheck<T: Config<I>, I: 'static = ()> = StorageValue<_, T::BlockNumber, ValueQuery>;
+
+    /// Governable `k` multiplier for `try_consensus`'s MAD outlier filter.
+    /// Defaults to `T::OutlierDeviationFactor` until retuned via
+    /// `set_consensus_outlier_factor`.
+    #[pallet::storage]
+    #[pallet::getter(fn consensus_outlier_factor)]
+    pub type ConsensusOutlierFactor<T: Config<I>, I: 'static = ()> = StorageValue<_, u32, OptionQuery>;
+
+    /// Name of the JSON field each source's HTTP response reports its figure
+    /// under, e.g. `b"value"` for `{"value": 105}`. Shared across sources.
+    #[pallet::storage]
+    #[pallet::getter(fn source_value_field)]
+    pub type SourceValueField<T, I = ()> = StorageValue<_, BoundedVec<u8, ConstU32<64>>, ValueQuery>;
+
+    /// Per-source weight used by `try_consensus`'s weighted average. Absent
+    /// until a round first runs or `set_source_reputation` is called, in
+    /// which case a source starts at `DEFAULT_REPUTATION`.
+    #[pallet::storage]
+    #[pallet::getter(fn source_reputation)]
+    pub type SourceReputation<T, I = ()> = StorageMap<_, Blake2_128Concat, Vec<u8>, u32, OptionQuery>;
+
+    /// Governable per-round reputation reward/decay step. Defaults to
+    /// `T::ReputationStepSize` until retuned via `set_reputation_step_size`.
+    #[pallet::storage]
+    #[pallet::getter(fn reputation_step)]
+    pub type ReputationStep<T: Config<I>, I: 'static = ()> = StorageValue<_, u32, OptionQuery>;
+
+    /// Furthest block a member's conviction-weighted vote weight is locked
+    /// until, tracked across every proposal they've voted on.
+    #[pallet::storage]
+    #[pallet::getter(fn vote_lock)]
+    pub type VoteLocks<T: Config<I>, I: 'static = ()> = StorageMap<_, Blake2_128Concat, T::AccountId, T::BlockNumber, ValueQuery>;
+
+    /// Council member whose explicit vote stands in, at final tally, for
+    /// every council member who never voted on a proposal. `None` disables
+    /// the default-vote behaviour.
+    #[pallet::storage]
+    #[pallet::getter(fn prime_member)]
+    pub type PrimeMember<T: Config<I>, I: 'static = ()> = StorageValue<_, T::AccountId, OptionQuery>;
+
+    /// Proposal hashes keyed by the block at which they're due for final
+    /// tally (their `end_block`). `on_initialize` drains each block's bucket
+    /// so a proposal is approved or expired the moment voting closes, instead
+    /// of waiting on some later `vote_on_proposal` call to trigger it.
+    #[pallet::storage]
+    #[pallet::getter(fn proposal_agenda)]
+    pub type ProposalAgenda<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::BlockNumber, BoundedVec<T::Hash, ConstU32<64>>, ValueQuery>;
+
+    /// Live council membership, kept sorted so `is_member`, `add_member` and
+    /// `remove_member` can binary-search it. Mutated by
+    /// `add_member`/`remove_member` or pushed to directly by an external
+    /// membership provider through `ChangeMembers`/`InitializeMembers`.
+    #[pallet::storage]
+    #[pallet::getter(fn members)]
+    pub type Members<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, BoundedVec<T::AccountId, T::MaxCouncilMembers>, ValueQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
-    pub enum Event<T: Config> {
+    pub enum Event<T: Config<I>, I: 'static = ()> {
         /// HOI value updated. [value]
         HOIUpdated { value: u32 },
         /// Oracle parameters updated
@@ -145,10 +601,51 @@ pub mod pallet {
         SourceAdded { source: Vec<u8> },
         /// Source removed from allowed sources
         SourceRemoved { source: Vec<u8> },
+        /// A signed observation was accepted into the current window.
+        ObservationSubmitted { source: Vec<u8>, submitter: T::AccountId, value: u32 },
+        /// The observation window for a source reached consensus and committed a
+        /// new HOI value derived from the median of its observations.
+        WindowConsensusReached { value: u32, observation_count: u32 },
+        /// Aggregation skipped the update because too few observations survived
+        /// staleness/outlier filtering to meet `MinSourcesForConsensus`.
+        ConsensusFailed { surviving_sources: u32, rejected_sources: u32 },
+        /// `try_consensus` skipped the update because too few sources had a
+        /// submission within `StalenessWindow` (or survived the MAD outlier
+        /// filter among those) to meet `MinSourcesForConsensus`.
+        ConsensusStale { surviving_sources: u32 },
+        /// A council member posted the liveness bond.
+        CouncilBondPosted { who: T::AccountId, amount: BalanceOf<T, I> },
+        /// A council member withdrew their liveness bond after a clean exit.
+        CouncilBondReturned { who: T::AccountId, amount: BalanceOf<T, I> },
+        /// A bonded council member neither heartbeated nor voted during the last
+        /// `VotingPeriod`.
+        CouncilMemberOffline { who: T::AccountId, missed: u32 },
+        /// A council member's bond was slashed for sustained inactivity.
+        CouncilMemberSlashed { who: T::AccountId, amount: BalanceOf<T, I> },
+        /// A proposal's vote tally met `RequiredMajority` and is now awaiting
+        /// its call to be resupplied to `enact_proposal`.
+        ProposalApproved { proposal_hash: T::Hash },
+        /// An approved proposal's call was resupplied, matched its stored
+        /// hash, and was dispatched.
+        ProposalEnacted { proposal_hash: T::Hash },
+        /// A proposal's voting period closed without `RequiredMajority` in
+        /// favour; it was discarded without effect.
+        ProposalExpired { proposal_hash: T::Hash },
+        /// The prime council member was (re)set, or cleared.
+        PrimeMemberSet { who: Option<T::AccountId> },
+        /// A source's reputation weight changed, either from a consensus
+        /// round's automatic reward/decay or a manual governance override.
+        SourceReputationUpdated { source: Vec<u8>, reputation: u32 },
+        /// A council member was added, either via `add_member` or an
+        /// external membership provider pushing through `ChangeMembers`.
+        MemberAdded { who: T::AccountId },
+        /// A council member was removed; any vote it cast on a still-`Active`
+        /// proposal was struck from that proposal's tally.
+        MemberRemoved { who: T::AccountId },
     }
 
     #[pallet::error]
-    pub enum Error<T> {
+    pub enum Error<T, I = ()> {
         /// Not a council member
         NotCouncilMember,
         /// Invalid source
@@ -177,22 +674,99 @@ pub mod pallet {
         SourceNotFound,
         /// Too many sources
         TooManySources,
+        /// Signer of an observation payload is not a council member.
+        SignerNotCouncilMember,
+        /// This account already submitted an observation for this source in the
+        /// current window.
+        DuplicateObservation,
+        /// Too many observations already recorded for this source this window.
+        TooManyObservations,
+        /// `submit_observation` is disabled on this runtime; `submit_source_value`/
+        /// `submit_signed_source_value` is the canonical consensus path. See
+        /// `Config::ObservationConsensusEnabled`.
+        ObservationConsensusDisabled,
+        /// The offchain HTTP fetch for a source failed or timed out.
+        FetchError,
+        /// This account already has a liveness bond posted.
+        BondAlreadyPosted,
+        /// This account has no liveness bond to withdraw.
+        NoBondPosted,
+        /// The bond can't be withdrawn while missed heartbeats are outstanding,
+        /// and neither the bond nor a conviction vote lock can be released
+        /// before the account's `VoteLocks` block has passed.
+        BondLocked,
+        /// The proposal agenda bucket for this proposal's `end_block` is full.
+        AgendaFull,
+        /// The proposed call's encoded length exceeds `MaxProposalLen`.
+        ProposalTooLarge,
+        /// The proposed call's dispatch weight exceeds `MaxProposalWeight`.
+        ProposalTooHeavy,
+        /// `enact_proposal` was called on a proposal that isn't `Approved`.
+        ProposalNotApproved,
+        /// The resupplied call doesn't hash (or doesn't encode) to what was
+        /// stored at `propose` time.
+        ProposalCallMismatch,
+        /// `GovernanceOrigin` couldn't produce a representative origin to
+        /// dispatch the enacted call from.
+        GovernanceOriginUnavailable,
+        /// A proposal's vote list is already at `MaxCouncilMembers`; this can
+        /// only happen if `MaxCouncilMembers` is set below the live council
+        /// size.
+        TooManyVotes,
+        /// The caller-supplied `voting_duration` is shorter than
+        /// `MinVotingDuration`.
+        DurationTooShort,
+        /// `add_member` was called with an account already in `Members`.
+        AlreadyMember,
+        /// `remove_member` was called with an account not in `Members`.
+        NotAMember,
+        /// `Members` is already at `MaxCouncilMembers`.
+        TooManyMembers,
     }
 
     #[pallet::hooks]
-    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+    impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+        fn on_initialize(now: T::BlockNumber) -> Weight {
+            let mut weight = Self::drain_proposal_agenda(now);
+
+            let voting_period = T::VotingPeriod::get();
+            if !voting_period.is_zero() && now.saturating_sub(Self::last_liveness_check()) >= voting_period {
+                <LastLivenessCheck<T, I>>::put(now);
+                weight = weight.saturating_add(Self::check_council_liveness(now));
+            }
+
+            weight
+        }
+
         fn offchain_worker(block_number: T::BlockNumber) {
-            if Self::should_fetch(block_number) {
-                if let Err(e) = Self::fetch_hoi_info() {
-                    log::error!("Error fetching HOI info: {:?}", e);
+            if !Self::should_fetch(block_number) {
+                return;
+            }
+
+            // Avoid two offchain workers racing to fetch/submit in the same slot.
+            let lock = StorageValueRef::persistent(b"halom_oracle::ocw_lock");
+            let already_run: Option<T::BlockNumber> = lock.get().ok().flatten();
+            if already_run == Some(block_number) {
+                return;
+            }
+            lock.set(&block_number);
+
+            for source in Self::allowed_sources().into_iter() {
+                if T::ObservationConsensusEnabled::get() {
+                    if let Err(e) = Self::fetch_and_submit_observation(source.clone(), block_number) {
+                        log::error!("Error fetching observation for source {:?}: {:?}", source, e);
+                    }
+                }
+                if let Err(e) = Self::fetch_and_submit_source_value(source.clone(), block_number) {
+                    log::error!("Error fetching source value for {:?}: {:?}", source, e);
                 }
             }
         }
     }
 
     #[pallet::call]
-    impl<T: Config> Pallet<T> {
-        #[pallet::weight(10_000)]
+    impl<T: Config<I>, I: 'static> Pallet<T, I> {
+        #[pallet::weight(T::WeightInfo::submit_hoi())]
         pub fn submit_hoi(
             origin: OriginFor<T>,
             value: u32,
@@ -205,18 +779,18 @@ pub mod pallet {
             
             ensure!(
                 now >= last_update.saturating_add(interval),
-                Error::<T>::TooEarlyToUpdate
+                Error::<T, I>::TooEarlyToUpdate
             );
             
-            <CurrentHOI<T>>::put(value);
-            <LastUpdate<T>>::put(now);
+            <CurrentHOI<T, I>>::put(value);
+            <LastUpdate<T, I>>::put(now);
             
             Self::deposit_event(Event::HOIUpdated { value });
             
             Ok(())
         }
 
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::update_parameters())]
         pub fn update_parameters(
             origin: OriginFor<T>,
             new_interval: T::BlockNumber,
@@ -226,31 +800,90 @@ pub mod pallet {
             
             ensure!(
                 new_interval >= T::MinUpdateInterval::get(),
-                Error::<T>::UpdateIntervalTooLow
+                Error::<T, I>::UpdateIntervalTooLow
             );
             
             ensure!(
                 new_interval <= T::MaxUpdateInterval::get(),
-                Error::<T>::UpdateIntervalTooHigh
+                Error::<T, I>::UpdateIntervalTooHigh
             );
             
             ensure!(
                 new_min_sources >= 1 && new_min_sources <= 10,
-                Error::<T>::InvalidMinSources
+                Error::<T, I>::InvalidMinSources
             );
             
-            <UpdateInterval<T>>::put(new_interval);
-            <MinSources<T>>::put(new_min_sources);
+            <UpdateInterval<T, I>>::put(new_interval);
+            <MinSources<T, I>>::put(new_min_sources);
             
             Self::deposit_event(Event::ParametersUpdated {
                 update_interval: new_interval,
                 min_sources: new_min_sources,
             });
-            
+
+            Ok(())
+        }
+
+        /// Retune the JSON field name the offchain worker parses each source's
+        /// figure out of. An empty name reverts to the naive first-numeric-run
+        /// fallback.
+        #[pallet::weight(T::WeightInfo::set_source_value_field())]
+        pub fn set_source_value_field(origin: OriginFor<T>, field: BoundedVec<u8, ConstU32<64>>) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+            <SourceValueField<T, I>>::put(field);
+            Ok(())
+        }
+
+        /// Retune `k`, the MAD outlier filter's deviation multiplier used by
+        /// `try_consensus`.
+        #[pallet::weight(T::WeightInfo::set_consensus_outlier_factor())]
+        pub fn set_consensus_outlier_factor(origin: OriginFor<T>, k: u32) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+            ensure!(k > 0, Error::<T, I>::InvalidParameterValue);
+            <ConsensusOutlierFactor<T, I>>::put(k);
+            Ok(())
+        }
+
+        /// Set or clear the prime council member, whose explicit vote stands
+        /// in for every council member who abstains on a proposal.
+        #[pallet::weight(T::WeightInfo::set_prime_member())]
+        pub fn set_prime_member(origin: OriginFor<T>, who: Option<T::AccountId>) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+            if let Some(ref prime) = who {
+                ensure!(Self::is_member(prime), Error::<T, I>::NotCouncilMember);
+            }
+            match &who {
+                Some(prime) => <PrimeMember<T, I>>::put(prime),
+                None => <PrimeMember<T, I>>::kill(),
+            }
+            Self::deposit_event(Event::PrimeMemberSet { who });
             Ok(())
         }
 
-        #[pallet::weight(10_000)]
+        /// Manually set (or reset to the default) an allowed source's
+        /// reputation weight, e.g. to rehabilitate a feed after a known,
+        /// now-resolved outage.
+        #[pallet::weight(T::WeightInfo::set_source_reputation())]
+        pub fn set_source_reputation(origin: OriginFor<T>, source: Vec<u8>, reputation: u32) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+            ensure!(Self::allowed_sources().iter().any(|s| s == &source), Error::<T, I>::SourceNotFound);
+            let reputation = reputation.min(T::MaxReputation::get());
+            <SourceReputation<T, I>>::insert(&source, reputation);
+            Self::deposit_event(Event::SourceReputationUpdated { source, reputation });
+            Ok(())
+        }
+
+        /// Retune the per-round reputation reward/decay step applied after
+        /// each `try_consensus` round.
+        #[pallet::weight(T::WeightInfo::set_reputation_step_size())]
+        pub fn set_reputation_step_size(origin: OriginFor<T>, step: u32) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+            ensure!(step > 0, Error::<T, I>::InvalidParameterValue);
+            <ReputationStep<T, I>>::put(step);
+            Ok(())
+        }
+
+        #[pallet::weight(T::WeightInfo::add_source())]
         pub fn add_source(
             origin: OriginFor<T>,
             source: Vec<u8>,
@@ -260,20 +893,20 @@ pub mod pallet {
             let mut sources = Self::allowed_sources();
             ensure!(
                 !sources.iter().any(|s| s == &source),
-                Error::<T>::SourceAlreadyExists
+                Error::<T, I>::SourceAlreadyExists
             );
             
             sources.try_push(source.clone())
-                .map_err(|_| Error::<T>::TooManySources)?;
+                .map_err(|_| Error::<T, I>::TooManySources)?;
             
-            <AllowedSources<T>>::put(sources);
+            <AllowedSources<T, I>>::put(sources);
             
             Self::deposit_event(Event::SourceAdded { source });
             
             Ok(())
         }
 
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::remove_source())]
         pub fn remove_source(
             origin: OriginFor<T>,
             source: Vec<u8>,
@@ -283,111 +916,393 @@ pub mod pallet {
             let mut sources = Self::allowed_sources();
             let pos = sources.iter()
                 .position(|s| s == &source)
-                .ok_or(Error::<T>::SourceNotFound)?;
+                .ok_or(Error::<T, I>::SourceNotFound)?;
             
             sources.remove(pos);
-            <AllowedSources<T>>::put(sources);
+            <AllowedSources<T, I>>::put(sources);
             
             Self::deposit_event(Event::SourceRemoved { source });
             
             Ok(())
         }
 
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::submit_source_value())]
         pub fn submit_source_value(
             origin: OriginFor<T>,
             source: Vec<u8>,
             value: u32,
         ) -> DispatchResult {
             T::OracleUpdateOrigin::ensure_origin(origin)?;
-            ensure!(Self::is_allowed_source(&source), Error::<T>::InvalidSource);
-            
-            <SourceValues<T>>::insert(source, value);
+            ensure!(Self::is_allowed_source(&source), Error::<T, I>::InvalidSource);
+
+            let now = frame_system::Pallet::<T>::block_number();
+            <SourceValues<T, I>>::insert(source, (value, now));
             Self::try_consensus()?;
             Ok(())
         }
 
-        #[pallet::weight(10_000)]
-        pub fn propose_parameter_change(
+        /// Unsigned-with-signed-payload counterpart to `submit_source_value`: an
+        /// offchain-worker-held oracle key reports a source's figure directly,
+        /// without needing `OracleUpdateOrigin`. `validate_unsigned` has already
+        /// checked `signature` against `payload.public`.
+        #[pallet::weight(T::WeightInfo::submit_signed_source_value())]
+        pub fn submit_signed_source_value(
+            origin: OriginFor<T>,
+            payload: HOIPayload<T::Public>,
+            signature: T::Signature,
+        ) -> DispatchResult {
+            let _ = signature;
+            ensure_none(origin)?;
+            ensure!(Self::is_allowed_source(&payload.source), Error::<T, I>::InvalidSource);
+
+            let now = frame_system::Pallet::<T>::block_number();
+            <SourceValues<T, I>>::insert(payload.source, (payload.hoi_value, now));
+            Self::try_consensus()?;
+            Ok(())
+        }
+
+        /// Record a single source observation submitted by an offchain-worker key
+        /// as an unsigned transaction with a signed payload. `validate_unsigned`
+        /// has already checked the signature against `payload.public` and that the
+        /// corresponding account is a council member; once at least
+        /// `MinSourcesForConsensus` distinct sources have reported within the
+        /// window, the median of per-source values is committed as the new HOI.
+        #[pallet::weight(T::WeightInfo::submit_observation())]
+        pub fn submit_observation(
+            origin: OriginFor<T>,
+            payload: SourceObservationPayload<T::Public, T::BlockNumber>,
+            signature: T::Signature,
+        ) -> DispatchResult {
+            let _ = signature;
+            ensure_none(origin)?;
+
+            ensure!(
+                T::ObservationConsensusEnabled::get(),
+                Error::<T, I>::ObservationConsensusDisabled
+            );
+            ensure!(Self::is_allowed_source(&payload.source_id), Error::<T, I>::InvalidSource);
+
+            let submitter = payload.public.clone().into_account();
+
+            <Observations<T, I>>::try_mutate(&payload.source_id, |observations| -> DispatchResult {
+                ensure!(
+                    !observations.iter().any(|(who, _, _)| who == &submitter),
+                    Error::<T, I>::DuplicateObservation
+                );
+                observations
+                    .try_push((submitter.clone(), payload.value, payload.block_number))
+                    .map_err(|_| Error::<T, I>::TooManyObservations)?;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::ObservationSubmitted {
+                source: payload.source_id.clone(),
+                submitter,
+                value: payload.value,
+            });
+
+            Self::try_settle_window(&payload.source_id)?;
+
+            Ok(())
+        }
+
+        /// Propose an arbitrary council-gated action, voted on for
+        /// `voting_duration` blocks (floored at `MinVotingDuration`, so an
+        /// urgent proposal can shorten deliberation but not skip it). Only
+        /// `call`'s hash and encoded length are kept in storage; `call`
+        /// itself must be resupplied to `enact_proposal` once this proposal
+        /// is `Approved`.
+        #[pallet::weight(T::WeightInfo::propose())]
+        pub fn propose(
             origin: OriginFor<T>,
-            parameter: Parameter<T::BlockNumber>,
+            call: Box<<T as Config<I>>::RuntimeCall>,
+            voting_duration: T::BlockNumber,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
-            ensure!(T::CouncilMembers::is_council_member(&who), Error::<T>::NotCouncilMember);
-            
-            // Validate parameter
-            match &parameter {
-                Parameter::UpdateInterval(interval) => {
-                    ensure!(*interval >= T::MinUpdateInterval::get(), Error::<T>::UpdateIntervalTooLow);
-                    ensure!(*interval <= T::MaxUpdateInterval::get(), Error::<T>::UpdateIntervalTooHigh);
-                },
-                Parameter::MinSources(sources) => {
-                    ensure!(*sources >= 1 && *sources <= 10, Error::<T>::InvalidMinSources);
-                },
-                Parameter::ConsensusThreshold(threshold) => {
-                    ensure!(*threshold > 0 && *threshold <= 100, Error::<T>::InvalidParameterValue);
-                },
-            }
-            
+            ensure!(Self::is_member(&who), Error::<T, I>::NotCouncilMember);
+            ensure!(voting_duration >= T::MinVotingDuration::get(), Error::<T, I>::DurationTooShort);
+
+            let call_len = call.encoded_size() as u32;
+            ensure!(call_len <= T::MaxProposalLen::get(), Error::<T, I>::ProposalTooLarge);
+            ensure!(
+                call.get_dispatch_info().weight.all_lte(T::MaxProposalWeight::get()),
+                Error::<T, I>::ProposalTooHeavy
+            );
+            let call_hash = T::Hashing::hash_of(&*call);
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let proposer_lock_until = Self::lock_vote(&who, Conviction::Locked1x, now);
+
+            let end_block = now.saturating_add(voting_duration);
+            let votes_for = BoundedVec::try_from(vec![(who.clone(), Conviction::Locked1x, proposer_lock_until)])
+                .map_err(|_| Error::<T, I>::TooManyVotes)?;
             let proposal = Proposal {
-                proposer: who.clone(),
-                parameter,
-                votes_for: vec![who],
-                votes_against: vec![],
-                end_block: frame_system::Pallet::<T>::block_number() + T::VotingPeriod::get(),
+                proposer: who,
+                call_hash,
+                call_len,
+                votes_for,
+                votes_against: Default::default(),
+                end_block,
                 status: ProposalStatus::Active,
             };
-            
+
             let hash = T::Hashing::hash_of(&proposal);
-            <Proposals<T>>::insert(hash, proposal);
-            
+            <ProposalAgenda<T, I>>::try_mutate(end_block, |bucket| bucket.try_push(hash))
+                .map_err(|_| Error::<T, I>::AgendaFull)?;
+            <Proposals<T, I>>::insert(hash, proposal);
+
+            Ok(())
+        }
+
+        /// Resupply an `Approved` proposal's call, verify it against the
+        /// stored hash/length, and dispatch it from `GovernanceOrigin`.
+        /// Callable by anyone: the vote already authorized the action, so
+        /// enactment is a permissionless formality once approved.
+        #[pallet::weight(T::WeightInfo::enact_proposal())]
+        pub fn enact_proposal(
+            origin: OriginFor<T>,
+            proposal_hash: T::Hash,
+            call: Box<<T as Config<I>>::RuntimeCall>,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            let proposal = <Proposals<T, I>>::get(proposal_hash).ok_or(Error::<T, I>::ProposalNotFound)?;
+            ensure!(proposal.status == ProposalStatus::Approved, Error::<T, I>::ProposalNotApproved);
+            ensure!(call.encoded_size() as u32 == proposal.call_len, Error::<T, I>::ProposalCallMismatch);
+            ensure!(T::Hashing::hash_of(&*call) == proposal.call_hash, Error::<T, I>::ProposalCallMismatch);
+
+            let governance_origin: T::RuntimeOrigin =
+                T::GovernanceOrigin::try_successful_origin().map_err(|_| Error::<T, I>::GovernanceOriginUnavailable)?;
+
+            <Proposals<T, I>>::remove(proposal_hash);
+            call.dispatch(governance_origin).map_err(|e| e.error)?;
+            Self::deposit_event(Event::ProposalEnacted { proposal_hash });
+
             Ok(())
         }
 
-        #[pallet::weight(10_000)]
+        /// Cast a conviction-weighted vote: `conviction` scales the vote's tally
+        /// weight and locks the voter's weight for a proportional number of
+        /// blocks, per the standard conviction doubling schedule.
+        #[pallet::weight(T::WeightInfo::vote_on_proposal(T::MaxCouncilMembers::get()))]
         pub fn vote_on_proposal(
             origin: OriginFor<T>,
             proposal_hash: T::Hash,
             approve: bool,
+            conviction: Conviction,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
-            ensure!(T::CouncilMembers::is_council_member(&who), Error::<T>::NotCouncilMember);
-            
-            let mut proposal = <Proposals<T>>::get(proposal_hash)
-                .ok_or(Error::<T>::ProposalNotFound)?;
-                
-            ensure!(proposal.status == ProposalStatus::Active, Error::<T>::ProposalExpired);
-            
+            ensure!(Self::is_member(&who), Error::<T, I>::NotCouncilMember);
+
+            let mut proposal = <Proposals<T, I>>::get(proposal_hash)
+                .ok_or(Error::<T, I>::ProposalNotFound)?;
+
+            ensure!(proposal.status == ProposalStatus::Active, Error::<T, I>::ProposalExpired);
+
             let current_block = frame_system::Pallet::<T>::block_number();
-            ensure!(current_block <= proposal.end_block, Error::<T>::ProposalExpired);
-            
+            ensure!(current_block <= proposal.end_block, Error::<T, I>::ProposalExpired);
+
             // Check if already voted
             ensure!(
-                !proposal.votes_for.contains(&who) && !proposal.votes_against.contains(&who),
-                Error::<T>::AlreadyVoted
+                !proposal.votes_for.iter().any(|(voter, _, _)| voter == &who)
+                    && !proposal.votes_against.iter().any(|(voter, _, _)| voter == &who),
+                Error::<T, I>::AlreadyVoted
             );
-            
+
+            let lock_until = Self::lock_vote(&who, conviction, current_block);
+
             if approve {
-                proposal.votes_for.push(who);
+                proposal.votes_for.try_push((who.clone(), conviction, lock_until))
+                    .map_err(|_| Error::<T, I>::TooManyVotes)?;
             } else {
-                proposal.votes_against.push(who);
+                proposal.votes_against.try_push((who.clone(), conviction, lock_until))
+                    .map_err(|_| Error::<T, I>::TooManyVotes)?;
             }
-            
+
+            Self::record_liveness(&who, current_block);
+
             // Check if proposal can be resolved
             if Self::should_resolve_proposal(&proposal) {
                 Self::resolve_proposal(proposal_hash, &mut proposal)?;
             }
-            
-            <Proposals<T>>::insert(proposal_hash, proposal);
+
+            <Proposals<T, I>>::insert(proposal_hash, proposal);
+            Ok(())
+        }
+
+        /// Reserve the liveness bond, enrolling the caller in the heartbeat
+        /// accountability program. Requires council membership.
+        #[pallet::weight(T::WeightInfo::post_council_bond())]
+        pub fn post_council_bond(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(Self::is_member(&who), Error::<T, I>::NotCouncilMember);
+            ensure!(!<CouncilBonds<T, I>>::contains_key(&who), Error::<T, I>::BondAlreadyPosted);
+
+            let amount = T::CouncilBond::get();
+            T::Currency::reserve(&who, amount)?;
+            <CouncilBonds<T, I>>::insert(&who, amount);
+            Self::record_liveness(&who, frame_system::Pallet::<T>::block_number());
+
+            Self::deposit_event(Event::CouncilBondPosted { who, amount });
+            Ok(())
+        }
+
+        /// Unreserve the liveness bond on a clean exit: no missed heartbeats
+        /// outstanding.
+        #[pallet::weight(T::WeightInfo::withdraw_council_bond())]
+        pub fn withdraw_council_bond(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let amount = <CouncilBonds<T, I>>::get(&who).ok_or(Error::<T, I>::NoBondPosted)?;
+            ensure!(Self::missed_heartbeats(&who) == 0, Error::<T, I>::BondLocked);
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(Self::vote_lock(&who) <= now, Error::<T, I>::BondLocked);
+
+            T::Currency::unreserve(&who, amount);
+            <CouncilBonds<T, I>>::remove(&who);
+            <LastLiveness<T, I>>::remove(&who);
+            <MissedHeartbeats<T, I>>::remove(&who);
+
+            Self::deposit_event(Event::CouncilBondReturned { who, amount });
+            Ok(())
+        }
+
+        /// Proof of life for a bonded council member, submitted as an ordinary
+        /// signed extrinsic from the member's own account. There is no
+        /// unsigned signed-payload variant, and the offchain worker does not
+        /// submit heartbeats.
+        #[pallet::weight(T::WeightInfo::submit_heartbeat())]
+        pub fn submit_heartbeat(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(<CouncilBonds<T, I>>::contains_key(&who), Error::<T, I>::NoBondPosted);
+
+            Self::record_liveness(&who, frame_system::Pallet::<T>::block_number());
+            Ok(())
+        }
+
+        /// Release `target`'s conviction-vote currency lock once it has
+        /// elapsed. Callable by anyone, mirroring `pallet_democracy::unlock`:
+        /// it only ever benefits the locked account, so there's no reason to
+        /// restrict who can trigger it.
+        #[pallet::weight(T::WeightInfo::unlock_vote_balance())]
+        pub fn unlock_vote_balance(origin: OriginFor<T>, target: T::AccountId) -> DispatchResult {
+            ensure_signed(origin)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(Self::vote_lock(&target) <= now, Error::<T, I>::BondLocked);
+            T::Currency::remove_lock(CONVICTION_VOTE_LOCK_ID, &target);
+            Ok(())
+        }
+
+        /// Add `who` to the live council membership. Equivalent to pushing a
+        /// single-account diff through `ChangeMembers::change_members_sorted`,
+        /// but callable directly under `MembershipOrigin` without standing up
+        /// an external membership-provider pallet.
+        #[pallet::weight(T::WeightInfo::add_member())]
+        pub fn add_member(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+            T::MembershipOrigin::ensure_origin(origin)?;
+            <Members<T, I>>::try_mutate(|members| -> DispatchResult {
+                let pos = members.binary_search(&who).err().ok_or(Error::<T, I>::AlreadyMember)?;
+                members.try_insert(pos, who.clone()).map_err(|_| Error::<T, I>::TooManyMembers)?;
+                Ok(())
+            })?;
+            Self::deposit_event(Event::MemberAdded { who });
+            Ok(())
+        }
+
+        /// Remove `who` from the live council membership, striking any vote
+        /// it cast on a still-`Active` proposal from that proposal's tally.
+        #[pallet::weight(T::WeightInfo::remove_member())]
+        pub fn remove_member(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+            T::MembershipOrigin::ensure_origin(origin)?;
+            <Members<T, I>>::try_mutate(|members| -> DispatchResult {
+                let pos = members.binary_search(&who).ok().ok_or(Error::<T, I>::NotAMember)?;
+                members.remove(pos);
+                Ok(())
+            })?;
+            Self::prune_votes_from(&[who.clone()]);
+            Self::deposit_event(Event::MemberRemoved { who });
             Ok(())
         }
     }
 
-    impl<T: Config> Pallet<T> {
+    impl<T: Config<I>, I: 'static> Pallet<T, I> {
+        /// Extend `who`'s conviction vote lock to cover a vote cast now with
+        /// `conviction`, returning the resulting `lock_until` for that vote.
+        /// `VoteLocks` tracks the furthest lock across every vote the member has
+        /// cast, mirroring how conviction locks a member's whole governance
+        /// weight rather than a single proposal's allocation of it.
+        ///
+        /// A non-zero conviction also places (or extends) a `LockableCurrency`
+        /// lock on `who`'s balance, same as `pallet_democracy`'s conviction
+        /// voting; `CouncilBond` stands in for a staked "vote amount" since
+        /// this pallet's votes aren't denominated in a token quantity.
+        fn lock_vote(who: &T::AccountId, conviction: Conviction, now: T::BlockNumber) -> T::BlockNumber {
+            let lock_until = now
+                .saturating_add(T::ConvictionVoteLockPeriod::get().saturating_mul(conviction.lock_periods().into()));
+            <VoteLocks<T, I>>::mutate(who, |existing| {
+                if lock_until > *existing {
+                    *existing = lock_until;
+                }
+            });
+
+            if conviction.lock_periods() > 0 {
+                T::Currency::set_lock(
+                    CONVICTION_VOTE_LOCK_ID,
+                    who,
+                    T::CouncilBond::get(),
+                    WithdrawReasons::TRANSFER,
+                );
+            }
+
+            lock_until
+        }
+
+        /// Mark a bonded member as alive at `now`, clearing any accumulated
+        /// missed-heartbeat count.
+        fn record_liveness(who: &T::AccountId, now: T::BlockNumber) {
+            <LastLiveness<T, I>>::insert(who, now);
+            <MissedHeartbeats<T, I>>::remove(who);
+        }
+
+        /// Run once per `VotingPeriod`: any bonded member who hasn't heartbeated
+        /// or voted since the previous check is marked offline, and slashed once
+        /// they cross `MaxMissedHeartbeats`.
+        fn check_council_liveness(now: T::BlockNumber) -> Weight {
+            let voting_period = T::VotingPeriod::get();
+            let mut reads = 0u64;
+            let mut writes = 0u64;
+
+            for (who, _bond) in <CouncilBonds<T, I>>::iter() {
+                reads += 1;
+                let last_seen = Self::last_liveness(&who);
+                if now.saturating_sub(last_seen) < voting_period {
+                    continue;
+                }
+
+                let missed = Self::missed_heartbeats(&who).saturating_add(1);
+                <MissedHeartbeats<T, I>>::insert(&who, missed);
+                writes += 1;
+                Self::deposit_event(Event::CouncilMemberOffline { who: who.clone(), missed });
+
+                if missed >= T::MaxMissedHeartbeats::get() {
+                    if let Some(bond) = <CouncilBonds<T, I>>::get(&who) {
+                        let penalty = T::LivenessPenalty::get() * bond;
+                        let (_imbalance, unslashed) = T::Currency::slash_reserved(&who, penalty);
+                        let slashed = penalty.saturating_sub(unslashed);
+                        <CouncilBonds<T, I>>::insert(&who, bond.saturating_sub(slashed));
+                        <MissedHeartbeats<T, I>>::insert(&who, 0);
+                        writes += 2;
+                        Self::deposit_event(Event::CouncilMemberSlashed { who, amount: slashed });
+                    }
+                }
+            }
+
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
         fn should_fetch(block_number: T::BlockNumber) -> bool {
             let last_update = Self::last_update();
-            let interval = T::OracleUpdateInterval::get().into();
-            
+            let interval = Self::update_interval();
+
             if last_update.is_zero() {
                 return true;
             }
@@ -396,27 +1311,178 @@ pub mod pallet {
             block_number > last_update + interval
         }
 
-        fn fetch_hoi_info() -> Result<(), Error<T>> {
-            // In a real implementation, this would fetch from an actual API
-            // For now, we'll use a mock value
-            let hoi_value = 105; // Example: 5% inflation
+        /// Fetch a single source's figure over HTTP(S) and submit it back on-chain
+        /// as a signed `submit_observation` call from a locally-held oracle key
+        /// whose account must be a live council member.
+        fn fetch_and_submit_observation(
+            source: Vec<u8>,
+            block_number: T::BlockNumber,
+        ) -> Result<(), Error<T, I>> {
+            let value = Self::fetch_source_value(&source)?;
 
-            // Submit transaction
-            let call = Call::submit_hoi_value { hoi_value };
-            let _ = SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into())
-                .map_err(|_| Error::<T>::FetchError)?;
+            let signer = Signer::<T, T::AuthorityId>::any_account();
+            let result = signer.send_unsigned_transaction(
+                |account| SourceObservationPayload {
+                    source_id: source.clone(),
+                    value,
+                    block_number,
+                    public: account.public.clone(),
+                },
+                |payload, signature| Call::submit_observation { payload, signature },
+            );
 
-            Ok(())
+            match result {
+                Some((_account, Ok(()))) => Ok(()),
+                Some((_account, Err(()))) => {
+                    log::error!("Failed to submit observation for source {:?}", source);
+                    Ok(())
+                }
+                None => {
+                    log::warn!("No local oracle account available to sign observation");
+                    Ok(())
+                }
+            }
+        }
+
+        /// Issue an HTTP(S) GET against the source's endpoint, with a retry on
+        /// failure, and parse a numeric figure out of the body within a short
+        /// deadline per attempt.
+        fn fetch_source_value(source: &[u8]) -> Result<u32, Error<T, I>> {
+            let url = Self::source_endpoint(source);
+
+            for attempt in 0..2 {
+                let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(3_000));
+                let outcome = http::Request::get(&url)
+                    .deadline(deadline)
+                    .send()
+                    .map_err(|_| Error::<T, I>::FetchError)
+                    .and_then(|pending| {
+                        pending
+                            .try_wait(deadline)
+                            .map_err(|_| Error::<T, I>::FetchError)?
+                            .map_err(|_| Error::<T, I>::FetchError)
+                    })
+                    .and_then(|response| {
+                        if response.code != 200 {
+                            return Err(Error::<T, I>::FetchError);
+                        }
+                        let body = response.body().collect::<Vec<u8>>();
+                        Self::parse_numeric_figure(&body)
+                    });
+
+                match outcome {
+                    Ok(value) => return Ok(value),
+                    Err(e) if attempt == 1 => return Err(e),
+                    Err(_) => continue,
+                }
+            }
+
+            Err(Error::<T, I>::FetchError)
+        }
+
+        /// Extract the figure reported under `SourceValueField` (e.g. the `105` in
+        /// `{"value": 105}`), falling back to the first numeric run in the body
+        /// when no field name has been configured.
+        fn parse_numeric_figure(body: &[u8]) -> Result<u32, Error<T, I>> {
+            let field = Self::source_value_field();
+            if !field.is_empty() {
+                if let Some(value) = Self::parse_json_field(body, &field) {
+                    return Ok(value);
+                }
+            }
+
+            let digits: Vec<u8> = body
+                .iter()
+                .copied()
+                .skip_while(|b| !b.is_ascii_digit())
+                .take_while(|b| b.is_ascii_digit())
+                .collect();
+
+            sp_std::str::from_utf8(&digits)
+                .ok()
+                .and_then(|s| s.parse::<u32>().ok())
+                .ok_or(Error::<T, I>::FetchError)
+        }
+
+        /// Find `"<field>":<digits>` in a JSON body and parse the digits. A naive
+        /// scan rather than a full JSON parser, since the figure is always a bare
+        /// number, never a nested object or string.
+        fn parse_json_field(body: &[u8], field: &[u8]) -> Option<u32> {
+            let mut needle = Vec::with_capacity(field.len() + 2);
+            needle.push(b'"');
+            needle.extend_from_slice(field);
+            needle.push(b'"');
+
+            let pos = body.windows(needle.len()).position(|w| w == needle.as_slice())?;
+            let after_key = &body[pos + needle.len()..];
+            let after_colon = after_key.iter().position(|b| *b == b':').map(|i| &after_key[i + 1..])?;
+
+            let digits: Vec<u8> = after_colon
+                .iter()
+                .copied()
+                .skip_while(|b| b.is_ascii_whitespace())
+                .take_while(|b| b.is_ascii_digit())
+                .collect();
+
+            sp_std::str::from_utf8(&digits).ok()?.parse::<u32>().ok()
+        }
+
+        /// Fetch and sign-submit a source's raw figure via `submit_signed_source_value`,
+        /// skipping if this worker already did so within the current `UpdateInterval`.
+        fn fetch_and_submit_source_value(source: Vec<u8>, block_number: T::BlockNumber) -> Result<(), Error<T, I>> {
+            let mut lock_key = b"halom_oracle::ocw_source_value::".to_vec();
+            lock_key.extend_from_slice(&source);
+            let lock = StorageValueRef::persistent(&lock_key);
+
+            let already_run: Option<T::BlockNumber> = lock.get().ok().flatten();
+            if let Some(last_run) = already_run {
+                if block_number.saturating_sub(last_run) < Self::update_interval() {
+                    return Ok(());
+                }
+            }
+
+            let value = Self::fetch_source_value(&source)?;
+
+            let signer = Signer::<T, T::AuthorityId>::any_account();
+            let result = signer.send_unsigned_transaction(
+                |account| HOIPayload {
+                    source: source.clone(),
+                    hoi_value: value,
+                    public: account.public.clone(),
+                },
+                |payload, signature| Call::submit_signed_source_value { payload, signature },
+            );
+
+            match result {
+                Some((_account, Ok(()))) => {
+                    lock.set(&block_number);
+                    Ok(())
+                }
+                Some((_account, Err(()))) => {
+                    log::error!("Failed to submit source value for {:?}", source);
+                    Ok(())
+                }
+                None => {
+                    log::warn!("No local oracle account available to sign source value");
+                    Ok(())
+                }
+            }
+        }
+
+        fn source_endpoint(source: &[u8]) -> Vec<u8> {
+            let mut url = b"https://oracle.halom.network/v1/sources/".to_vec();
+            url.extend_from_slice(source);
+            url
         }
 
         /// Initialize default values
         pub fn initialize_defaults() -> Weight {
-            if !<UpdateInterval<T>>::exists() {
-                <UpdateInterval<T>>::put(T::MinUpdateInterval::get());
+            if !<UpdateInterval<T, I>>::exists() {
+                <UpdateInterval<T, I>>::put(T::MinUpdateInterval::get());
             }
             
-            if !<MinSources<T>>::exists() {
-                <MinSources<T>>::put(T::MinSourcesForConsensus::get());
+            if !<MinSources<T, I>>::exists() {
+                <MinSources<T, I>>::put(T::MinSourcesForConsensus::get());
             }
             
             T::DbWeight::get().writes(2)
@@ -426,106 +1492,454 @@ pub mod pallet {
             Self::allowed_sources().iter().any(|s| s == source)
         }
 
+        /// Manipulation-resistant consensus over the raw `SourceValues` map:
+        /// drop any source whose last submission is older than
+        /// `StalenessWindow`, then reject outliers via median absolute
+        /// deviation among what's left, before publishing. Skips the update
+        /// (emitting `ConsensusStale`) rather than erroring when too few
+        /// sources survive either filter.
+        ///
+        /// This pallet also carries a second, independently-built consensus
+        /// path (`submit_observation` → [`Self::try_settle_window`]); this one
+        /// is canonical and always live, while the other is gated off by
+        /// `Config::ObservationConsensusEnabled` to keep the two from racing
+        /// to commit `CurrentHOI` in the same block.
         fn try_consensus() -> DispatchResult {
-            let values = <SourceValues<T>>::iter().collect::<Vec<_>>();
-            
-            ensure!(
-                values.len() >= T::MinSourcesForConsensus::get() as usize,
-                Error::<T>::InsufficientSources
-            );
-            
-            // Calculate weighted average
-            let sum: u32 = values.iter().map(|(_, v)| v).sum();
-            let avg = sum / (values.len() as u32);
-            
-            Self::submit_hoi(frame_system::RawOrigin::Root.into(), avg)
+            let min_sources = T::MinSourcesForConsensus::get() as usize;
+            let now = frame_system::Pallet::<T>::block_number();
+            let staleness_window = T::StalenessWindow::get();
+
+            let reports: Vec<(Vec<u8>, u32)> = <SourceValues<T, I>>::iter()
+                .filter_map(|(source, (value, last_seen))| {
+                    (now.saturating_sub(last_seen) <= staleness_window).then_some((source, value))
+                })
+                .collect();
+
+            if reports.len() < min_sources {
+                Self::deposit_event(Event::ConsensusStale { surviving_sources: reports.len() as u32 });
+                return Ok(());
+            }
+
+            let mut values: Vec<u32> = reports.iter().map(|(_, v)| *v).collect();
+            values.sort_unstable();
+            let median = median_of(&values);
+
+            let k = Self::consensus_outlier_factor().unwrap_or_else(T::OutlierDeviationFactor::get);
+            let mut deviations: Vec<u32> =
+                values.iter().map(|v| v.abs_diff(median)).collect();
+            deviations.sort_unstable();
+            let mad = median_of(&deviations);
+            let threshold = k.saturating_mul(mad);
+
+            let survivors: Vec<(Vec<u8>, u32)> = if mad == 0 {
+                reports
+            } else {
+                reports.into_iter().filter(|(_, v)| v.abs_diff(median) <= threshold).collect()
+            };
+
+            if survivors.len() < min_sources {
+                Self::deposit_event(Event::ConsensusStale { surviving_sources: survivors.len() as u32 });
+                return Ok(());
+            }
+
+            // Reputation-weighted average: each surviving source's value
+            // contributes proportionally to its current weight, rather than
+            // every source counting equally.
+            let mut weighted_sum: u64 = 0;
+            let mut weight_total: u64 = 0;
+            for (source, value) in survivors.iter() {
+                let weight = Self::source_reputation(source).unwrap_or(DEFAULT_REPUTATION) as u64;
+                weighted_sum = weighted_sum.saturating_add(*value as u64 * weight);
+                weight_total = weight_total.saturating_add(weight);
+            }
+            let consensus_value = if weight_total == 0 {
+                median_of(&survivors.iter().map(|(_, v)| *v).collect::<Vec<_>>())
+            } else {
+                (weighted_sum / weight_total) as u32
+            };
+
+            Self::update_source_reputations(&survivors, consensus_value);
+
+            Self::submit_hoi(frame_system::RawOrigin::Root.into(), consensus_value)
         }
 
-        fn should_resolve_proposal(proposal: &Proposal<T::AccountId, T::BlockNumber>) -> bool {
-            let total_votes = proposal.votes_for.len() + proposal.votes_against.len();
-            let required_votes = T::RequiredMajority::get() as usize;
-            
-            proposal.votes_for.len() >= required_votes || 
-            proposal.votes_against.len() >= required_votes ||
-            total_votes >= T::RequiredMajority::get() as usize
+        /// Reward every source whose value survived outlier rejection and
+        /// landed within `ReputationTolerance` of the agreed result; decay
+        /// everything else (rejected outliers, plus allowed sources that
+        /// didn't submit at all this round) toward `MinReputation`.
+        fn update_source_reputations(survivors: &[(Vec<u8>, u32)], consensus_value: u32) {
+            let step = Self::reputation_step().unwrap_or_else(T::ReputationStepSize::get);
+            let tolerance = T::ReputationTolerance::get().mul_ceil(consensus_value.max(1));
+
+            for source in Self::allowed_sources().iter() {
+                let current = Self::source_reputation(source).unwrap_or(DEFAULT_REPUTATION);
+                let rewarded = survivors
+                    .iter()
+                    .any(|(s, v)| s == source && v.abs_diff(consensus_value) <= tolerance);
+
+                let updated = if rewarded {
+                    current.saturating_add(step).min(T::MaxReputation::get())
+                } else {
+                    current.saturating_sub(step).max(T::MinReputation::get())
+                };
+
+                if updated != current {
+                    <SourceReputation<T, I>>::insert(source, updated);
+                    Self::deposit_event(Event::SourceReputationUpdated {
+                        source: source.clone(),
+                        reputation: updated,
+                    });
+                }
+            }
         }
 
+        /// Once at least `MinSourcesForConsensus` distinct sources have reported
+        /// an observation within the current window, commit the median across all
+        /// sources' latest values as the new HOI and clear the window.
+        ///
+        /// ## Two consensus paths
+        ///
+        /// This pallet carries two independently-built routes to the same
+        /// `submit_hoi` call:
+        ///
+        /// - `submit_source_value` / `submit_signed_source_value` → [`Self::try_consensus`],
+        ///   weighting survivors by `SourceReputation` and filtering on
+        ///   `StalenessWindow`, over the flat `SourceValues` map. This is the
+        ///   canonical path and always live.
+        /// - `submit_observation` → `try_settle_window` (here), aggregating via
+        ///   the pluggable `T::Aggregator` over `Observations`, filtered on
+        ///   `MaxObservationAge` instead, with no reputation weighting.
+        ///
+        /// Both can independently satisfy `MinSourcesForConsensus` and dispatch
+        /// `submit_hoi`, so leaving both live against the same `AllowedSources`
+        /// set would let whichever extrinsic happens to land first in a block
+        /// silently override the other's aggregation. `submit_observation`
+        /// (and hence this function) is therefore gated off by
+        /// `Config::ObservationConsensusEnabled` — `submit_observation` returns
+        /// `Error::ObservationConsensusDisabled` and the offchain worker stops
+        /// submitting to it before this is ever reached, unless a runtime
+        /// opts back in for this path instead of `try_consensus`.
+        fn try_settle_window(source_id: &[u8]) -> DispatchResult {
+            let reporting_sources = <Observations<T, I>>::iter_keys().count() as u32;
+            if reporting_sources < T::MinSourcesForConsensus::get() {
+                return Ok(());
+            }
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let max_age = T::MaxObservationAge::get();
+
+            let fresh: Vec<(T::AccountId, u32)> = <Observations<T, I>>::iter_values()
+                .filter_map(|obs| {
+                    obs.last().and_then(|(who, value, reported_at)| {
+                        if now.saturating_sub(*reported_at) <= max_age {
+                            Some((who.clone(), *value))
+                        } else {
+                            None
+                        }
+                    })
+                })
+                .collect();
+
+            let min_sources = T::MinSourcesForConsensus::get();
+            match T::Aggregator::aggregate(&fresh, min_sources) {
+                Some((value, rejected)) => {
+                    let surviving = fresh.len() as u32 - rejected.len() as u32;
+
+                    Self::submit_hoi(frame_system::RawOrigin::Root.into(), value)?;
+                    Self::deposit_event(Event::WindowConsensusReached {
+                        value,
+                        observation_count: surviving,
+                    });
+                }
+                None => {
+                    Self::deposit_event(Event::ConsensusFailed {
+                        surviving_sources: fresh.len() as u32,
+                        rejected_sources: reporting_sources.saturating_sub(fresh.len() as u32),
+                    });
+                }
+            }
+
+            let _ = source_id;
+            let _ = <Observations<T, I>>::clear(u32::MAX, None);
+
+            Ok(())
+        }
+
+        /// Sum of `conviction.multiplier()` across a conviction-weighted vote tally.
+        fn weighted_tally(votes: &[(T::AccountId, Conviction, T::BlockNumber)]) -> u32 {
+            votes.iter().map(|(_, conviction, _)| conviction.multiplier()).sum()
+        }
+
+        fn should_resolve_proposal(proposal: &Proposal<T::AccountId, T::BlockNumber, T::Hash, T::MaxCouncilMembers>) -> bool {
+            let for_weight = Self::weighted_tally(&proposal.votes_for);
+            let against_weight = Self::weighted_tally(&proposal.votes_against);
+            let total_weight = for_weight.saturating_add(against_weight);
+            if total_weight == 0 {
+                return false;
+            }
+            let approval_permille = for_weight.saturating_mul(100) / total_weight;
+            approval_permille >= T::RequiredMajority::get()
+                || (100 - approval_permille) >= T::RequiredMajority::get()
+        }
+
+        /// Resolve a proposal early, once its weighted tally already meets
+        /// `RequiredMajority` either way. Approval only flips `status` to
+        /// `Approved`; the call itself is dispatched separately by
+        /// `enact_proposal` once resupplied.
         fn resolve_proposal(
             proposal_hash: T::Hash,
-            proposal: &mut Proposal<T::AccountId, T::BlockNumber>
+            proposal: &mut Proposal<T::AccountId, T::BlockNumber, T::Hash, T::MaxCouncilMembers>
         ) -> DispatchResult {
-            let total_votes = proposal.votes_for.len() + proposal.votes_against.len();
-            let approval_threshold = (total_votes * T::RequiredMajority::get() as usize) / 100;
-            
-            if proposal.votes_for.len() >= approval_threshold {
+            let for_weight = Self::weighted_tally(&proposal.votes_for);
+            let against_weight = Self::weighted_tally(&proposal.votes_against);
+            let total_weight = for_weight.saturating_add(against_weight);
+            let approval_permille = if total_weight == 0 { 0 } else { for_weight.saturating_mul(100) / total_weight };
+
+            if approval_permille >= T::RequiredMajority::get() {
                 proposal.status = ProposalStatus::Approved;
-                Self::enact_proposal(proposal)?;
+                Self::deposit_event(Event::ProposalApproved { proposal_hash });
             } else {
                 proposal.status = ProposalStatus::Rejected;
             }
-            
+
             Ok(())
         }
 
-        fn enact_proposal(proposal: &Proposal<T::AccountId, T::BlockNumber>) -> DispatchResult {
-            match &proposal.parameter {
-                Parameter::UpdateInterval(interval) => {
-                    <UpdateInterval<T>>::put(interval);
-                },
-                Parameter::MinSources(sources) => {
-                    <MinSources<T>>::put(sources);
-                },
-                Parameter::ConsensusThreshold(_threshold) => {
-                    // Implement consensus threshold update
-                },
+        /// Drain the proposal agenda bucket due at `now`, finally tallying every
+        /// proposal still found there. Bounded by `MaxAgendaItemsPerBlock`: any
+        /// excess is carried over into the next block's bucket rather than
+        /// processed in one go, the same agenda-hole pattern `pallet_scheduler`
+        /// uses to bound per-block weight.
+        fn drain_proposal_agenda(now: T::BlockNumber) -> Weight {
+            let bucket = <ProposalAgenda<T, I>>::take(now);
+            let mut items: Vec<T::Hash> = bucket.into_inner();
+            let mut weight = T::DbWeight::get().reads_writes(1, 1);
+
+            let limit = T::MaxAgendaItemsPerBlock::get() as usize;
+            if items.len() > limit {
+                let overflow = items.split_off(limit);
+                let carried_to = now.saturating_add(One::one());
+                <ProposalAgenda<T, I>>::mutate(carried_to, |next_bucket| {
+                    for hash in overflow {
+                        let _ = next_bucket.try_push(hash);
+                    }
+                });
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+            }
+
+            for hash in items {
+                weight = weight.saturating_add(Self::finalize_proposal(hash));
+            }
+
+            weight
+        }
+
+        /// Final tally for a proposal whose voting period has closed: approve
+        /// if quorum was met and `RequiredMajority` was met by weighted
+        /// turnout (awaiting `enact_proposal`), otherwise mark it `Expired`
+        /// without effect. A proposal already resolved early by
+        /// `resolve_proposal` (status no longer `Active`) is left untouched.
+        ///
+        /// Before tallying, every council member who never voted is folded in
+        /// as voting the same way as the prime member (if one is set and
+        /// voted explicitly) — `pallet_collective`'s prime-member default
+        /// vote, applied here instead of per-vote so it only ever affects the
+        /// final, end-of-period outcome.
+        fn finalize_proposal(proposal_hash: T::Hash) -> Weight {
+            let Some(mut proposal) = <Proposals<T, I>>::get(proposal_hash) else {
+                return T::DbWeight::get().reads(1);
+            };
+            if proposal.status != ProposalStatus::Active {
+                return T::DbWeight::get().reads(1);
+            }
+
+            let council_size = Self::members().len() as u32;
+            let explicit_voters = (proposal.votes_for.len() + proposal.votes_against.len()) as u32;
+            let quorum_met = explicit_voters >= T::MinQuorum::get().mul_ceil(council_size);
+
+            let mut for_weight = Self::weighted_tally(&proposal.votes_for);
+            let mut against_weight = Self::weighted_tally(&proposal.votes_against);
+
+            if let Some(prime) = Self::prime_member() {
+                let prime_vote = proposal.votes_for.iter().any(|(voter, _, _)| voter == &prime)
+                    .then_some(true)
+                    .or_else(|| proposal.votes_against.iter().any(|(voter, _, _)| voter == &prime).then_some(false));
+                if let Some(approve) = prime_vote {
+                    let absent = council_size.saturating_sub(explicit_voters);
+                    if approve {
+                        for_weight = for_weight.saturating_add(absent);
+                    } else {
+                        against_weight = against_weight.saturating_add(absent);
+                    }
+                }
+            }
+
+            let total_weight = for_weight.saturating_add(against_weight);
+            let approval_permille =
+                if total_weight == 0 { 0 } else { for_weight.saturating_mul(100) / total_weight };
+
+            if quorum_met && approval_permille >= T::RequiredMajority::get() {
+                proposal.status = ProposalStatus::Approved;
+                Self::deposit_event(Event::ProposalApproved { proposal_hash });
+            } else {
+                proposal.status = ProposalStatus::Expired;
+                Self::deposit_event(Event::ProposalExpired { proposal_hash });
+            }
+
+            <Proposals<T, I>>::insert(proposal_hash, proposal);
+            T::DbWeight::get().reads_writes(2, 2)
+        }
+
+        /// Whether `who` is in the live council membership.
+        fn is_member(who: &T::AccountId) -> bool {
+            Self::members().binary_search(who).is_ok()
+        }
+
+        /// Strike any vote cast by an account in `outgoing` from every
+        /// still-`Active` proposal's tally, so a removed member can't keep
+        /// influencing a vote after it leaves. Mirrors how
+        /// `pallet_collective` discards a departed member's votes on its own
+        /// open motions when membership changes.
+        fn prune_votes_from(outgoing: &[T::AccountId]) {
+            for (proposal_hash, mut proposal) in <Proposals<T, I>>::iter() {
+                if proposal.status != ProposalStatus::Active {
+                    continue;
+                }
+                let before = proposal.votes_for.len() + proposal.votes_against.len();
+                proposal.votes_for.retain(|(voter, _, _)| !outgoing.contains(voter));
+                proposal.votes_against.retain(|(voter, _, _)| !outgoing.contains(voter));
+                if proposal.votes_for.len() + proposal.votes_against.len() != before {
+                    <Proposals<T, I>>::insert(proposal_hash, proposal);
+                }
+            }
+        }
+
+        /// Seed the live council membership at genesis, keeping it sorted so
+        /// `is_member`'s binary search and later `change_members_sorted` diffs
+        /// stay valid.
+        fn seed_members(members: &[T::AccountId]) {
+            let mut sorted = members.to_vec();
+            sorted.sort();
+            if let Ok(bounded) = BoundedVec::try_from(sorted) {
+                <Members<T, I>>::put(bounded);
+            }
+        }
+    }
+
+    impl<T: Config<I>, I: 'static> ChangeMembers<T::AccountId> for Pallet<T, I> {
+        /// Apply an externally-computed sorted diff to `Members`, e.g. one
+        /// pushed in by a `pallet_membership` instance. `incoming`/`outgoing`
+        /// are assumed sorted per the `ChangeMembers` contract; `new` is the
+        /// full resulting sorted membership and is stored as-is rather than
+        /// recomputed, matching `pallet_collective`'s own implementation.
+        fn change_members_sorted(
+            incoming: &[T::AccountId],
+            outgoing: &[T::AccountId],
+            new: &[T::AccountId],
+        ) {
+            if let Ok(bounded) = BoundedVec::try_from(new.to_vec()) {
+                <Members<T, I>>::put(bounded);
+            }
+            Self::prune_votes_from(outgoing);
+            for who in incoming {
+                Self::deposit_event(Event::MemberAdded { who: who.clone() });
+            }
+            for who in outgoing {
+                Self::deposit_event(Event::MemberRemoved { who: who.clone() });
+            }
+        }
+    }
+
+    impl<T: Config<I>, I: 'static> InitializeMembers<T::AccountId> for Pallet<T, I> {
+        /// Seed `Members` the first time an external membership provider
+        /// initializes, e.g. from its own genesis config. A no-op once the
+        /// pallet already has members, since that can only happen via its
+        /// own genesis build.
+        fn initialize_members(members: &[T::AccountId]) {
+            if Self::members().is_empty() {
+                Self::seed_members(members);
             }
-            Ok(())
         }
     }
 
     #[pallet::genesis_config]
-    pub struct GenesisConfig<T: Config> {
+    pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
         pub initial_sources: Vec<Vec<u8>>,
-        pub _phantom: PhantomData<T>,
+        pub initial_members: Vec<T::AccountId>,
+        pub _phantom: PhantomData<(T, I)>,
     }
 
     #[cfg(feature = "std")]
-    impl<T: Config> Default for GenesisConfig<T> {
+    impl<T: Config<I>, I: 'static> Default for GenesisConfig<T, I> {
         fn default() -> Self {
             Self {
                 initial_sources: Vec::new(),
+                initial_members: Vec::new(),
                 _phantom: PhantomData,
             }
         }
     }
 
     #[pallet::genesis_build]
-    impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+    impl<T: Config<I>, I: 'static> GenesisBuild<T, I> for GenesisConfig<T, I> {
         fn build(&self) {
             if let Ok(sources) = BoundedVec::try_from(self.initial_sources.clone()) {
-                <AllowedSources<T>>::put(sources);
+                <AllowedSources<T, I>>::put(sources);
             }
-            
-            Pallet::<T>::initialize_defaults();
+
+            Pallet::<T, I>::seed_members(&self.initial_members);
+            Pallet::<T, I>::initialize_defaults();
         }
     }
 
     #[pallet::validate_unsigned]
-    impl<T: Config> ValidateUnsigned for Pallet<T> {
-        type Call = Call<T>;
+    impl<T: Config<I>, I: 'static> ValidateUnsigned for Pallet<T, I> {
+        type Call = Call<T, I>;
 
         fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
-            if let Call::submit_hoi_value { hoi_value } = call {
-                if hoi_value > &0 {
-                    return Ok(ValidTransaction::with_tag_prefix("HalomOracle")
-                        .priority(100)
-                        .and_provides(("hoi-oracle", *hoi_value))
-                        .longevity(5)
-                        .propagate(true)
-                        .build());
+            if let Call::submit_observation { payload, signature } = call {
+                if !T::ObservationConsensusEnabled::get() {
+                    return InvalidTransaction::Call.into();
+                }
+
+                let signature_valid = SignedPayload::<T>::verify::<T::AuthorityId>(payload, signature.clone());
+                if !signature_valid {
+                    return InvalidTransaction::BadProof.into();
                 }
+
+                let submitter = payload.public.clone().into_account();
+                if !Self::is_member(&submitter) {
+                    return InvalidTransaction::BadSigner.into();
+                }
+
+                return ValidTransaction::with_tag_prefix("HalomOracleObservation")
+                    .priority(100)
+                    .and_provides((submitter, payload.source_id.clone(), payload.block_number))
+                    .longevity(5)
+                    .propagate(true)
+                    .build();
             }
+
+            if let Call::submit_signed_source_value { payload, signature } = call {
+                let signature_valid = SignedPayload::<T>::verify::<T::AuthorityId>(payload, signature.clone());
+                if !signature_valid {
+                    return InvalidTransaction::BadProof.into();
+                }
+
+                let submitter = payload.public.clone().into_account();
+                if !Self::is_member(&submitter) {
+                    return InvalidTransaction::BadSigner.into();
+                }
+
+                return ValidTransaction::with_tag_prefix("HalomOracleSourceValue")
+                    .priority(100)
+                    .and_provides((submitter, payload.source.clone()))
+                    .longevity(5)
+                    .propagate(true)
+                    .build();
+            }
+
             InvalidTransaction::Call.into()
         }
     }
-} 
\ No newline at end of file
+}
\ No newline at end of file
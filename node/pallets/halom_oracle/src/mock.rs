@@ -1,26 +1,34 @@
 use crate as pallet_halom_oracle;
 use frame_support::{
     parameter_types,
-    traits::{ConstU32, ConstU64, EnsureOrigin},
+    traits::{ConstU32, ConstU64},
+    weights::Weight,
 };
-use frame_system as system;
-use sp_core::H256;
+use frame_system::EnsureRoot;
+use pallet_halom_oracle::MedianAbsoluteDeviation;
+use sp_core::{Pair, H256};
 use sp_runtime::{
-    traits::{BlakeTwo256, IdentityLookup},
-    BuildStorage,
+    testing::TestXt,
+    traits::{BlakeTwo256, IdentifyAccount, IdentityLookup, Verify},
+    BuildStorage, MultiSignature, Permill,
 };
 
 type Block = frame_system::mocking::MockBlock<Test>;
+type Balance = u128;
+type Signature = MultiSignature;
+type AccountId = <<Signature as Verify>::Signer as IdentifyAccount>::AccountId;
+type Extrinsic = TestXt<RuntimeCall, ()>;
 
 // Configure a mock runtime to test the pallet.
 frame_support::construct_runtime!(
     pub enum Test {
         System: frame_system,
+        Balances: pallet_balances,
         HalomOracle: pallet_halom_oracle,
     }
 );
 
-impl system::Config for Test {
+impl frame_system::Config for Test {
     type BaseCallFilter = frame_support::traits::Everything;
     type BlockWeights = ();
     type BlockLength = ();
@@ -30,14 +38,14 @@ impl system::Config for Test {
     type Nonce = u64;
     type Hash = H256;
     type Hashing = BlakeTwo256;
-    type AccountId = u64;
+    type AccountId = AccountId;
     type Lookup = IdentityLookup<Self::AccountId>;
     type Block = Block;
     type RuntimeEvent = RuntimeEvent;
     type BlockHashCount = ConstU64<250>;
     type Version = ();
     type PalletInfo = PalletInfo;
-    type AccountData = ();
+    type AccountData = pallet_balances::AccountData<Balance>;
     type OnNewAccount = ();
     type OnKilledAccount = ();
     type SystemWeightInfo = ();
@@ -46,12 +54,54 @@ impl system::Config for Test {
     type MaxConsumers = ConstU32<16>;
 }
 
-// Mock council members
-pub struct MockCouncilMembers;
-impl pallet_halom_oracle::IsCouncilMember<u64> for MockCouncilMembers {
-    fn is_council_member(who: &u64) -> bool {
-        // Test accounts 1, 2, and 3 are council members
-        matches!(who, 1 | 2 | 3)
+parameter_types! {
+    pub const ExistentialDeposit: Balance = 1;
+}
+
+impl pallet_balances::Config for Test {
+    type MaxLocks = ConstU32<50>;
+    type MaxReserves = ConstU32<50>;
+    type ReserveIdentifier = [u8; 8];
+    type Balance = Balance;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type FreezeIdentifier = ();
+    type MaxFreezes = ();
+    type RuntimeHoldReason = ();
+    type MaxHolds = ();
+}
+
+// Offchain-worker signed-transaction plumbing: `submit_observation` is submitted
+// as an unsigned transaction carrying a signed payload, so `Test` must satisfy
+// `pallet_halom_oracle::Config`'s `CreateSignedTransaction` supertrait bound the
+// same way the real runtime does.
+impl frame_system::offchain::SigningTypes for Test {
+    type Public = <Signature as Verify>::Signer;
+    type Signature = Signature;
+}
+
+impl<LocalCall> frame_system::offchain::SendTransactionTypes<LocalCall> for Test
+where
+    RuntimeCall: From<LocalCall>,
+{
+    type OverarchingCall = RuntimeCall;
+    type Extrinsic = Extrinsic;
+}
+
+impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Test
+where
+    RuntimeCall: From<LocalCall>,
+{
+    fn create_transaction<C: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>>(
+        call: RuntimeCall,
+        _public: <Signature as Verify>::Signer,
+        _account: AccountId,
+        nonce: u64,
+    ) -> Option<(RuntimeCall, <Extrinsic as sp_runtime::traits::Extrinsic>::SignaturePayload)> {
+        Some((call, (nonce, ())))
     }
 }
 
@@ -61,49 +111,101 @@ parameter_types! {
     pub const MinSourcesForConsensus: u32 = 2;
     pub const VotingPeriod: u64 = 50;
     pub const RequiredMajority: u32 = 66;
-}
-
-pub struct MockOrigin;
-impl EnsureOrigin<RuntimeOrigin> for MockOrigin {
-    type Success = ();
-    fn try_origin(o: RuntimeOrigin) -> Result<Self::Success, RuntimeOrigin> {
-        if let Ok(who) = ensure_signed(o.clone()) {
-            if MockCouncilMembers::is_council_member(&who) {
-                return Ok(());
-            }
-        }
-        Err(o)
-    }
+    pub const OutlierDeviationFactor: u32 = 3;
+    pub const MaxObservationAge: u64 = 20;
+    pub const CouncilBond: Balance = 100;
+    pub const LivenessPenalty: Permill = Permill::from_percent(10);
+    pub const MaxMissedHeartbeats: u32 = 3;
+    pub const ConvictionVoteLockPeriod: u64 = 10;
+    pub const MaxAgendaItemsPerBlock: u32 = 10;
+    pub const MaxProposalLen: u32 = 1_024;
+    pub const MaxProposalWeight: Weight = Weight::from_parts(1_000_000_000, 0);
+    pub const MinQuorum: Permill = Permill::from_percent(50);
+    pub const MaxReputation: u32 = 200;
+    pub const MinReputation: u32 = 10;
+    pub const ReputationStepSize: u32 = 5;
+    pub const ReputationTolerance: Permill = Permill::from_percent(5);
+    pub const StalenessWindow: u64 = 20;
+    pub const MaxCouncilMembers: u32 = 10;
+    pub const MinVotingDuration: u64 = 5;
+    // Kept enabled here (unlike the runtime) so this pallet's own tests can
+    // still exercise submit_observation/try_settle_window while that code
+    // path exists.
+    pub const ObservationConsensusEnabled: bool = true;
 }
 
 impl pallet_halom_oracle::Config for Test {
     type RuntimeEvent = RuntimeEvent;
-    type OracleUpdateOrigin = MockOrigin;
-    type GovernanceOrigin = MockOrigin;
-    type CouncilMembers = MockCouncilMembers;
+    type AuthorityId = pallet_halom_oracle::crypto::OracleAuthId;
+    type Aggregator = MedianAbsoluteDeviation<OutlierDeviationFactor>;
+    type OutlierDeviationFactor = OutlierDeviationFactor;
+    type MaxObservationAge = MaxObservationAge;
+    type ObservationConsensusEnabled = ObservationConsensusEnabled;
+    type OracleUpdateOrigin = EnsureRoot<AccountId>;
+    type GovernanceOrigin = EnsureRoot<AccountId>;
+    type MembershipOrigin = EnsureRoot<AccountId>;
     type VotingPeriod = VotingPeriod;
     type MinUpdateInterval = MinUpdateInterval;
     type MaxUpdateInterval = MaxUpdateInterval;
     type MinSourcesForConsensus = MinSourcesForConsensus;
     type RequiredMajority = RequiredMajority;
+    type Currency = Balances;
+    type CouncilBond = CouncilBond;
+    type LivenessPenalty = LivenessPenalty;
+    type MaxMissedHeartbeats = MaxMissedHeartbeats;
+    type ConvictionVoteLockPeriod = ConvictionVoteLockPeriod;
+    type MaxAgendaItemsPerBlock = MaxAgendaItemsPerBlock;
+    type RuntimeCall = RuntimeCall;
+    type MaxProposalLen = MaxProposalLen;
+    type MaxProposalWeight = MaxProposalWeight;
+    type MinQuorum = MinQuorum;
+    type MaxReputation = MaxReputation;
+    type MinReputation = MinReputation;
+    type ReputationStepSize = ReputationStepSize;
+    type ReputationTolerance = ReputationTolerance;
+    type StalenessWindow = StalenessWindow;
+    type MaxCouncilMembers = MaxCouncilMembers;
+    type MinVotingDuration = MinVotingDuration;
+    type WeightInfo = ();
+}
+
+/// Well-known sr25519 test accounts (`//Alice`, `//Bob`, `//Charlie`), seeded as
+/// the genesis council. `submit_observation`'s signature check needs real
+/// sr25519 keypairs, so the mock can no longer get away with bare `u64`s.
+pub fn alice() -> AccountId {
+    sp_core::sr25519::Pair::from_string("//Alice", None)
+        .unwrap()
+        .public()
+        .into()
+}
+
+pub fn bob() -> AccountId {
+    sp_core::sr25519::Pair::from_string("//Bob", None)
+        .unwrap()
+        .public()
+        .into()
+}
+
+pub fn charlie() -> AccountId {
+    sp_core::sr25519::Pair::from_string("//Charlie", None)
+        .unwrap()
+        .public()
+        .into()
 }
 
 // Helper function to build genesis storage
 pub fn new_test_ext() -> sp_io::TestExternalities {
-    let mut t = system::GenesisConfig::<Test>::default()
+    let mut t = frame_system::GenesisConfig::<Test>::default()
         .build_storage()
         .unwrap();
-        
+
     pallet_halom_oracle::GenesisConfig::<Test> {
-        initial_sources: vec![
-            b"KSH".to_vec(),
-            b"MNB".to_vec(),
-            b"EUROSTAT".to_vec(),
-        ],
+        initial_sources: vec![b"KSH".to_vec(), b"MNB".to_vec(), b"EUROSTAT".to_vec()],
+        initial_members: vec![alice(), bob(), charlie()],
         _phantom: Default::default(),
     }
     .assimilate_storage(&mut t)
     .unwrap();
-    
+
     t.into()
-} 
\ No newline at end of file
+}
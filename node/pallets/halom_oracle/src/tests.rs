@@ -1,273 +1,239 @@
 #![cfg(test)]
 
-use crate::{mock::*, Error, Event, Parameter, ProposalStatus};
-use frame_support::{assert_noop, assert_ok};
-use sp_runtime::traits::BadOrigin;
-use sp_runtime::traits::Hash;
+use crate::{mock::*, Error, Event, SourceObservationPayload};
+use codec::Encode;
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
+use sp_core::{
+    offchain::{testing::TestOffchainExt, OffchainDbExt, OffchainWorkerExt},
+    sr25519, Pair,
+};
+use sp_runtime::{
+    traits::ValidateUnsigned,
+    transaction_validity::{InvalidTransaction, TransactionSource},
+    MultiSignature, MultiSigner,
+};
+
+fn alice_pair() -> sr25519::Pair {
+    sr25519::Pair::from_string("//Alice", None).unwrap()
+}
+
+fn observation_payload(
+    who: &sr25519::Pair,
+    source_id: &[u8],
+    value: u32,
+    block_number: u64,
+) -> (SourceObservationPayload<MultiSigner, u64>, MultiSignature) {
+    let payload = SourceObservationPayload {
+        source_id: source_id.to_vec(),
+        value,
+        block_number,
+        public: MultiSigner::Sr25519(who.public()),
+    };
+    let signature = MultiSignature::Sr25519(who.sign(&payload.encode()));
+    (payload, signature)
+}
 
 #[test]
-fn test_submit_hoi_value() {
+fn submit_hoi_requires_oracle_update_origin() {
     new_test_ext().execute_with(|| {
-        System::set_block_number(1);
+        // Clears `TooEarlyToUpdate`: genesis seeds `UpdateInterval` from
+        // `MinUpdateInterval` (10), checked against `LastUpdate` (0).
+        System::set_block_number(20);
 
-        // Test submitting a valid HOI value
-        assert_ok!(HalomOracle::submit_hoi_value(RuntimeOrigin::signed(1), 105));
+        assert_noop!(
+            HalomOracle::submit_hoi(RuntimeOrigin::signed(alice()), 105),
+            sp_runtime::DispatchError::BadOrigin,
+        );
 
-        // Check that the event was emitted
+        assert_ok!(HalomOracle::submit_hoi(RuntimeOrigin::root(), 105));
         System::assert_last_event(Event::HOIUpdated { value: 105 }.into());
-
-        // Check that the storage was updated
         assert_eq!(HalomOracle::current_hoi(), 105);
-        assert_eq!(HalomOracle::last_update(), 1);
     });
 }
 
 #[test]
-fn test_invalid_hoi_value() {
+fn add_source_requires_governance_origin() {
     new_test_ext().execute_with(|| {
-        // Test submitting an invalid HOI value (0)
         assert_noop!(
-            HalomOracle::submit_hoi_value(RuntimeOrigin::signed(1), 0),
-            Error::<Test>::InvalidHOIValue
+            HalomOracle::add_source(RuntimeOrigin::signed(alice()), b"GUS".to_vec()),
+            sp_runtime::DispatchError::BadOrigin,
+        );
+
+        assert_ok!(HalomOracle::add_source(RuntimeOrigin::root(), b"GUS".to_vec()));
+        assert!(HalomOracle::allowed_sources().iter().any(|s| s == b"GUS"));
+
+        assert_noop!(
+            HalomOracle::add_source(RuntimeOrigin::root(), b"GUS".to_vec()),
+            Error::<Test>::SourceAlreadyExists,
         );
     });
 }
 
 #[test]
-fn test_update_interval() {
+fn submit_observation_records_a_fresh_observation() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
 
-        // Submit initial value
-        assert_ok!(HalomOracle::submit_hoi_value(RuntimeOrigin::signed(1), 105));
-
-        // Check that should_fetch returns false before interval
-        assert!(!HalomOracle::should_fetch(5));
+        let (payload, signature) = observation_payload(&alice_pair(), b"KSH", 100, 1);
+        assert_ok!(HalomOracle::submit_observation(
+            RuntimeOrigin::none(),
+            payload,
+            signature
+        ));
 
-        // Check that should_fetch returns true after interval
-        assert!(HalomOracle::should_fetch(12));
+        System::assert_last_event(
+            Event::ObservationSubmitted {
+                source: b"KSH".to_vec(),
+                submitter: alice(),
+                value: 100,
+            }
+            .into(),
+        );
     });
 }
 
 #[test]
-fn test_unsigned_validation() {
+fn submit_observation_rejects_duplicate_from_same_submitter() {
     new_test_ext().execute_with(|| {
-        // Test unsigned validation with valid value
-        let call = crate::Call::submit_hoi_value { hoi_value: 105 };
-        assert!(HalomOracle::validate_unsigned(sp_runtime::transaction_validity::TransactionSource::Local, &call).is_ok());
-
-        // Test unsigned validation with invalid value
-        let call = crate::Call::submit_hoi_value { hoi_value: 0 };
-        assert!(HalomOracle::validate_unsigned(sp_runtime::transaction_validity::TransactionSource::Local, &call).is_err());
-    });
-}
+        System::set_block_number(1);
 
-#[test]
-fn test_submit_source_value() {
-    new_test_ext().execute_with(|| {
-        // Submit value from KSH source
-        assert_ok!(HalomOracle::submit_source_value(
-            RuntimeOrigin::signed(1),
-            b"KSH".to_vec(),
-            520  // 5.2%
+        let (payload, signature) = observation_payload(&alice_pair(), b"KSH", 100, 1);
+        assert_ok!(HalomOracle::submit_observation(
+            RuntimeOrigin::none(),
+            payload.clone(),
+            signature.clone()
         ));
-        
-        // Check source value is stored
-        assert_eq!(HalomOracle::source_values(b"KSH".to_vec()), 520);
-        
-        // Submit value from invalid source
+
         assert_noop!(
-            HalomOracle::submit_source_value(
-                RuntimeOrigin::signed(1),
-                b"INVALID".to_vec(),
-                520
-            ),
-            Error::<Test>::InvalidSource
+            HalomOracle::submit_observation(RuntimeOrigin::none(), payload, signature),
+            Error::<Test>::DuplicateObservation,
         );
     });
 }
 
 #[test]
-fn test_consensus_calculation() {
+fn submit_observation_settles_window_once_enough_sources_report() {
     new_test_ext().execute_with(|| {
-        // Submit values from multiple sources
-        assert_ok!(HalomOracle::submit_source_value(
-            RuntimeOrigin::signed(1),
-            b"KSH".to_vec(),
-            520  // 5.2%
+        // Clears `TooEarlyToUpdate` once the window settles and `try_settle_window`
+        // dispatches `submit_hoi` internally (see the sibling test above).
+        System::set_block_number(20);
+
+        let (ksh_payload, ksh_sig) = observation_payload(&alice_pair(), b"KSH", 100, 20);
+        assert_ok!(HalomOracle::submit_observation(
+            RuntimeOrigin::none(),
+            ksh_payload,
+            ksh_sig
         ));
-        
-        assert_ok!(HalomOracle::submit_source_value(
-            RuntimeOrigin::signed(1),
-            b"MNB".to_vec(),
-            540  // 5.4%
+
+        let bob_pair = sr25519::Pair::from_string("//Bob", None).unwrap();
+        let (mnb_payload, mnb_sig) = observation_payload(&bob_pair, b"MNB", 104, 20);
+        assert_ok!(HalomOracle::submit_observation(
+            RuntimeOrigin::none(),
+            mnb_payload,
+            mnb_sig
         ));
-        
-        // Check consensus value (average)
-        assert_eq!(HalomOracle::current_hoi(), 530);  // 5.3%
+
+        // Two distinct sources reporting close values clears MinSourcesForConsensus
+        // and the MAD filter, so the window settles and `submit_hoi` is dispatched
+        // under the hood via `OracleUpdateOrigin` (root).
+        System::assert_has_event(
+            Event::WindowConsensusReached {
+                value: 102,
+                observation_count: 2,
+            }
+            .into(),
+        );
+        assert_eq!(HalomOracle::current_hoi(), 102);
     });
 }
 
 #[test]
-fn test_propose_parameter_change() {
+fn validate_unsigned_accepts_a_correctly_signed_observation() {
     new_test_ext().execute_with(|| {
-        // Create proposal from council member
-        assert_ok!(HalomOracle::propose_parameter_change(
-            RuntimeOrigin::signed(1),
-            Parameter::MinSources(3)
-        ));
-        
-        // Try to create proposal from non-council member
-        assert_noop!(
-            HalomOracle::propose_parameter_change(
-                RuntimeOrigin::signed(4),
-                Parameter::MinSources(3)
-            ),
-            Error::<Test>::NotCouncilMember
-        );
-        
-        // Try to create proposal with invalid value
-        assert_noop!(
-            HalomOracle::propose_parameter_change(
-                RuntimeOrigin::signed(1),
-                Parameter::MinSources(11)
-            ),
-            Error::<Test>::InvalidMinSources
-        );
+        let (payload, signature) = observation_payload(&alice_pair(), b"KSH", 100, 1);
+        let call = crate::Call::<Test>::submit_observation { payload, signature };
+
+        assert!(HalomOracle::validate_unsigned(TransactionSource::Local, &call).is_ok());
     });
 }
 
 #[test]
-fn test_vote_on_proposal() {
+fn validate_unsigned_rejects_a_tampered_signature() {
     new_test_ext().execute_with(|| {
-        // Create proposal
-        assert_ok!(HalomOracle::propose_parameter_change(
-            RuntimeOrigin::signed(1),
-            Parameter::MinSources(3)
-        ));
-        
-        // Get proposal hash
-        let proposal = HalomOracle::proposals(0).unwrap();
-        let hash = <Test as frame_system::Config>::Hashing::hash_of(&proposal);
-        
-        // Vote from another council member
-        assert_ok!(HalomOracle::vote_on_proposal(
-            RuntimeOrigin::signed(2),
-            hash,
-            true
-        ));
-        
-        // Try to vote again
-        assert_noop!(
-            HalomOracle::vote_on_proposal(
-                RuntimeOrigin::signed(2),
-                hash,
-                true
-            ),
-            Error::<Test>::AlreadyVoted
-        );
-        
-        // Vote from non-council member
-        assert_noop!(
-            HalomOracle::vote_on_proposal(
-                RuntimeOrigin::signed(4),
-                hash,
-                true
-            ),
-            Error::<Test>::NotCouncilMember
+        let (payload, _signature) = observation_payload(&alice_pair(), b"KSH", 100, 1);
+        let bogus_signature = MultiSignature::Sr25519(alice_pair().sign(b"not the payload"));
+        let call = crate::Call::<Test>::submit_observation {
+            payload,
+            signature: bogus_signature,
+        };
+
+        assert_eq!(
+            HalomOracle::validate_unsigned(TransactionSource::Local, &call),
+            Err(InvalidTransaction::BadProof.into()),
         );
     });
 }
 
 #[test]
-fn test_proposal_approval() {
+fn validate_unsigned_rejects_a_non_member_signer() {
     new_test_ext().execute_with(|| {
-        // Create proposal
-        assert_ok!(HalomOracle::propose_parameter_change(
-            RuntimeOrigin::signed(1),
-            Parameter::MinSources(3)
-        ));
-        
-        let proposal = HalomOracle::proposals(0).unwrap();
-        let hash = <Test as frame_system::Config>::Hashing::hash_of(&proposal);
-        
-        // Get required votes for approval
-        let required_votes = 2;  // 66% of 3 council members
-        
-        // Vote from council members
-        assert_ok!(HalomOracle::vote_on_proposal(
-            RuntimeOrigin::signed(2),
-            hash,
-            true
-        ));
-        
-        // Check proposal is approved and parameter is updated
-        let updated_proposal = HalomOracle::proposals(hash).unwrap();
-        assert_eq!(updated_proposal.status, ProposalStatus::Approved);
-        assert_eq!(HalomOracle::min_sources(), 3);
+        let outsider = sr25519::Pair::from_string("//Dave", None).unwrap();
+        let (payload, signature) = observation_payload(&outsider, b"KSH", 100, 1);
+        let call = crate::Call::<Test>::submit_observation { payload, signature };
+
+        assert_eq!(
+            HalomOracle::validate_unsigned(TransactionSource::Local, &call),
+            Err(InvalidTransaction::BadSigner.into()),
+        );
     });
 }
 
 #[test]
-fn test_proposal_rejection() {
+fn membership_is_managed_through_members_storage_under_membership_origin() {
     new_test_ext().execute_with(|| {
-        // Create proposal
-        assert_ok!(HalomOracle::propose_parameter_change(
-            RuntimeOrigin::signed(1),
-            Parameter::MinSources(3)
-        ));
-        
-        let proposal = HalomOracle::proposals(0).unwrap();
-        let hash = <Test as frame_system::Config>::Hashing::hash_of(&proposal);
-        
-        // Vote against from council members
-        assert_ok!(HalomOracle::vote_on_proposal(
-            RuntimeOrigin::signed(2),
-            hash,
-            false
-        ));
-        
-        assert_ok!(HalomOracle::vote_on_proposal(
-            RuntimeOrigin::signed(3),
-            hash,
-            false
-        ));
-        
-        // Check proposal is rejected
-        let updated_proposal = HalomOracle::proposals(hash).unwrap();
-        assert_eq!(updated_proposal.status, ProposalStatus::Rejected);
-        
-        // Check parameter is not updated
-        assert_eq!(HalomOracle::min_sources(), 2);
+        let dave = sr25519::Pair::from_string("//Dave", None).unwrap().public().into();
+
+        assert!(!HalomOracle::members().contains(&dave));
+        assert_noop!(
+            HalomOracle::add_member(RuntimeOrigin::signed(alice()), dave),
+            sp_runtime::DispatchError::BadOrigin,
+        );
+
+        assert_ok!(HalomOracle::add_member(RuntimeOrigin::root(), dave));
+        assert!(HalomOracle::members().contains(&dave));
+        System::assert_last_event(Event::MemberAdded { who: dave }.into());
+
+        assert_noop!(
+            HalomOracle::add_member(RuntimeOrigin::root(), dave),
+            Error::<Test>::AlreadyMember,
+        );
+
+        assert_ok!(HalomOracle::remove_member(RuntimeOrigin::root(), dave));
+        assert!(!HalomOracle::members().contains(&dave));
+        System::assert_last_event(Event::MemberRemoved { who: dave }.into());
     });
 }
 
 #[test]
-fn test_proposal_expiry() {
-    new_test_ext().execute_with(|| {
-        // Create proposal
-        assert_ok!(HalomOracle::propose_parameter_change(
-            RuntimeOrigin::signed(1),
-            Parameter::MinSources(3)
-        ));
-        
-        let proposal = HalomOracle::proposals(0).unwrap();
-        let hash = <Test as frame_system::Config>::Hashing::hash_of(&proposal);
-        
-        // Advance blocks past voting period
-        let voting_period = VotingPeriod::get();
-        for _ in 0..voting_period + 1 {
-            System::set_block_number(System::block_number() + 1);
-        }
-        
-        // Try to vote on expired proposal
-        assert_noop!(
-            HalomOracle::vote_on_proposal(
-                RuntimeOrigin::signed(2),
-                hash,
-                true
-            ),
-            Error::<Test>::ProposalExpired
-        );
+fn offchain_worker_lock_prevents_a_second_run_in_the_same_block() {
+    let mut ext = new_test_ext();
+    let (offchain, _state) = TestOffchainExt::new();
+    ext.register_extension(OffchainDbExt::new(offchain.clone()));
+    ext.register_extension(OffchainWorkerExt::new(offchain));
+
+    ext.execute_with(|| {
+        System::set_block_number(1);
+
+        // Pretend a worker already ran this block: `StorageValueRef` local
+        // storage is populated directly, the same way `offchain_worker` itself
+        // would leave it after a first run.
+        sp_runtime::offchain::storage::StorageValueRef::persistent(b"halom_oracle::ocw_lock")
+            .set(&1u64);
+
+        // With no HTTP request expectations registered, a second attempt to
+        // fetch/submit would panic inside `TestOffchainExt` -- reaching the end
+        // of `offchain_worker` without panicking demonstrates the lock short-
+        // circuited before any network access was attempted.
+        HalomOracle::offchain_worker(1);
     });
-} 
\ No newline at end of file
+}
@@ -0,0 +1,304 @@
+//! Autogenerated weights for `pallet_halom_oracle`.
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0
+//! DATE: 2026-07-30, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `halom-ci`, CPU: `Intel(R) Xeon(R) Platinum 8259CL CPU @ 2.50GHz`
+//! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_halom_oracle`.
+pub trait WeightInfo {
+    fn submit_hoi() -> Weight;
+    fn update_parameters() -> Weight;
+    fn set_source_value_field() -> Weight;
+    fn set_consensus_outlier_factor() -> Weight;
+    fn set_prime_member() -> Weight;
+    fn set_source_reputation() -> Weight;
+    fn set_reputation_step_size() -> Weight;
+    fn add_source() -> Weight;
+    fn remove_source() -> Weight;
+    fn submit_source_value() -> Weight;
+    fn submit_signed_source_value() -> Weight;
+    fn submit_observation() -> Weight;
+    fn propose() -> Weight;
+    fn enact_proposal() -> Weight;
+    fn vote_on_proposal(v: u32) -> Weight;
+    fn post_council_bond() -> Weight;
+    fn withdraw_council_bond() -> Weight;
+    fn submit_heartbeat() -> Weight;
+    fn unlock_vote_balance() -> Weight;
+    fn add_member() -> Weight;
+    fn remove_member() -> Weight;
+}
+
+/// Weights for `pallet_halom_oracle` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    /// Storage: `HalomOracle::LastUpdate` (r:1 w:1)
+    /// Storage: `HalomOracle::UpdateInterval` (r:1 w:0)
+    /// Storage: `HalomOracle::CurrentHOI` (r:0 w:1)
+    fn submit_hoi() -> Weight {
+        Weight::from_parts(12_500_000, 3_593)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+    /// Storage: `HalomOracle::UpdateInterval` (r:0 w:1)
+    /// Storage: `HalomOracle::MinSources` (r:0 w:1)
+    fn update_parameters() -> Weight {
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+    /// Storage: `HalomOracle::SourceValueField` (r:0 w:1)
+    fn set_source_value_field() -> Weight {
+        Weight::from_parts(8_500_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `HalomOracle::ConsensusOutlierFactor` (r:0 w:1)
+    fn set_consensus_outlier_factor() -> Weight {
+        Weight::from_parts(8_000_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `HalomOracle::Members` (r:1 w:0)
+    /// Storage: `HalomOracle::PrimeMember` (r:0 w:1)
+    fn set_prime_member() -> Weight {
+        Weight::from_parts(11_000_000, 1_887)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `HalomOracle::AllowedSources` (r:1 w:0)
+    /// Storage: `HalomOracle::SourceReputation` (r:0 w:1)
+    fn set_source_reputation() -> Weight {
+        Weight::from_parts(11_500_000, 1_449)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `HalomOracle::ReputationStep` (r:0 w:1)
+    fn set_reputation_step_size() -> Weight {
+        Weight::from_parts(8_000_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `HalomOracle::AllowedSources` (r:1 w:1)
+    fn add_source() -> Weight {
+        Weight::from_parts(12_000_000, 1_449)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `HalomOracle::AllowedSources` (r:1 w:1)
+    fn remove_source() -> Weight {
+        Weight::from_parts(12_000_000, 1_449)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `HalomOracle::AllowedSources` (r:1 w:0)
+    /// Storage: `HalomOracle::SourceValues` (r:0 w:1)
+    /// Storage: `HalomOracle::LastUpdate` (r:1 w:1)
+    /// Storage: `HalomOracle::UpdateInterval` (r:1 w:0)
+    /// Storage: `HalomOracle::CurrentHOI` (r:0 w:1)
+    fn submit_source_value() -> Weight {
+        Weight::from_parts(24_000_000, 5_042)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+    /// Storage: `HalomOracle::AllowedSources` (r:1 w:0)
+    /// Storage: `HalomOracle::SourceValues` (r:0 w:1)
+    /// Storage: `HalomOracle::LastUpdate` (r:1 w:1)
+    /// Storage: `HalomOracle::UpdateInterval` (r:1 w:0)
+    /// Storage: `HalomOracle::CurrentHOI` (r:0 w:1)
+    fn submit_signed_source_value() -> Weight {
+        Weight::from_parts(25_500_000, 5_042)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+    /// Storage: `HalomOracle::AllowedSources` (r:1 w:0)
+    /// Storage: `HalomOracle::Observations` (r:1 w:1)
+    fn submit_observation() -> Weight {
+        Weight::from_parts(20_000_000, 3_593)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `HalomOracle::Members` (r:1 w:0)
+    /// Storage: `HalomOracle::VoteLocks` (r:1 w:1)
+    /// Storage: `HalomOracle::ProposalAgenda` (r:1 w:1)
+    /// Storage: `HalomOracle::Proposals` (r:0 w:1)
+    fn propose() -> Weight {
+        Weight::from_parts(32_000_000, 7_531)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+    /// Storage: `HalomOracle::Proposals` (r:1 w:1)
+    fn enact_proposal() -> Weight {
+        Weight::from_parts(22_000_000, 4_042)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `HalomOracle::Members` (r:1 w:0)
+    /// Storage: `HalomOracle::Proposals` (r:1 w:1)
+    /// Storage: `HalomOracle::VoteLocks` (r:1 w:1)
+    /// Storage: `HalomOracle::LastLiveness` (r:0 w:1)
+    /// Storage: `HalomOracle::MissedHeartbeats` (r:0 w:1)
+    ///
+    /// The range of component `v` is `[1, 100]`.
+    fn vote_on_proposal(v: u32) -> Weight {
+        Weight::from_parts(28_000_000, 8_168)
+            // Standard Error: 2_284
+            .saturating_add(Weight::from_parts(38_000, 0).saturating_mul(v as u64))
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(4))
+    }
+    /// Storage: `HalomOracle::Members` (r:1 w:0)
+    /// Storage: `HalomOracle::CouncilBonds` (r:1 w:1)
+    /// Storage: `HalomOracle::LastLiveness` (r:0 w:1)
+    /// Storage: `HalomOracle::MissedHeartbeats` (r:0 w:1)
+    fn post_council_bond() -> Weight {
+        Weight::from_parts(18_000_000, 3_805)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+    /// Storage: `HalomOracle::CouncilBonds` (r:1 w:1)
+    /// Storage: `HalomOracle::MissedHeartbeats` (r:1 w:1)
+    /// Storage: `HalomOracle::VoteLocks` (r:1 w:0)
+    /// Storage: `HalomOracle::LastLiveness` (r:0 w:1)
+    fn withdraw_council_bond() -> Weight {
+        Weight::from_parts(20_000_000, 4_254)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+    /// Storage: `HalomOracle::CouncilBonds` (r:1 w:0)
+    /// Storage: `HalomOracle::LastLiveness` (r:0 w:1)
+    /// Storage: `HalomOracle::MissedHeartbeats` (r:0 w:1)
+    fn submit_heartbeat() -> Weight {
+        Weight::from_parts(11_000_000, 1_887)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+    /// Storage: `HalomOracle::VoteLocks` (r:1 w:0)
+    fn unlock_vote_balance() -> Weight {
+        Weight::from_parts(13_000_000, 1_887)
+            .saturating_add(T::DbWeight::get().reads(1))
+    }
+    /// Storage: `HalomOracle::Members` (r:1 w:1)
+    fn add_member() -> Weight {
+        Weight::from_parts(14_000_000, 1_887)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `HalomOracle::Members` (r:1 w:1)
+    /// Storage: `HalomOracle::Proposals` (r:1 w:1)
+    fn remove_member() -> Weight {
+        Weight::from_parts(20_000_000, 4_042)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+    fn submit_hoi() -> Weight {
+        Weight::from_parts(12_500_000, 3_593)
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+    fn update_parameters() -> Weight {
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+    fn set_source_value_field() -> Weight {
+        Weight::from_parts(8_500_000, 0)
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+    fn set_consensus_outlier_factor() -> Weight {
+        Weight::from_parts(8_000_000, 0)
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+    fn set_prime_member() -> Weight {
+        Weight::from_parts(11_000_000, 1_887)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+    fn set_source_reputation() -> Weight {
+        Weight::from_parts(11_500_000, 1_449)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+    fn set_reputation_step_size() -> Weight {
+        Weight::from_parts(8_000_000, 0)
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+    fn add_source() -> Weight {
+        Weight::from_parts(12_000_000, 1_449)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+    fn remove_source() -> Weight {
+        Weight::from_parts(12_000_000, 1_449)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+    fn submit_source_value() -> Weight {
+        Weight::from_parts(24_000_000, 5_042)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+    fn submit_signed_source_value() -> Weight {
+        Weight::from_parts(25_500_000, 5_042)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+    fn submit_observation() -> Weight {
+        Weight::from_parts(20_000_000, 3_593)
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+    fn propose() -> Weight {
+        Weight::from_parts(32_000_000, 7_531)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+    fn enact_proposal() -> Weight {
+        Weight::from_parts(22_000_000, 4_042)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+    fn vote_on_proposal(v: u32) -> Weight {
+        Weight::from_parts(28_000_000, 8_168)
+            .saturating_add(Weight::from_parts(38_000, 0).saturating_mul(v as u64))
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(4))
+    }
+    fn post_council_bond() -> Weight {
+        Weight::from_parts(18_000_000, 3_805)
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+    fn withdraw_council_bond() -> Weight {
+        Weight::from_parts(20_000_000, 4_254)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+    fn submit_heartbeat() -> Weight {
+        Weight::from_parts(11_000_000, 1_887)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+    fn unlock_vote_balance() -> Weight {
+        Weight::from_parts(13_000_000, 1_887)
+            .saturating_add(RocksDbWeight::get().reads(1))
+    }
+    fn add_member() -> Weight {
+        Weight::from_parts(14_000_000, 1_887)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+    fn remove_member() -> Weight {
+        Weight::from_parts(20_000_000, 4_042)
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+}
@@ -2,11 +2,16 @@
 
 pub use pallet::*;
 
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
 #[frame_support::pallet]
 pub mod pallet {
     use frame_support::{
         pallet_prelude::*,
-        traits::{Currency, Get, ReservableCurrency, ExistenceRequirement},
+        traits::{Currency, EnsureOrigin, Get, OnUnbalanced, ReservableCurrency, ExistenceRequirement, WithdrawReasons},
         PalletId,
     };
     use frame_system::pallet_prelude::*;
@@ -18,12 +23,55 @@ pub mod pallet {
     use pallet_halom_oracle::CurrentHOI;
 
     type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+    type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::NegativeImbalance;
 
     pub const INITIAL_SUPPLY: u128 = 10_000_000; // 10 millió kezdeti supply
     pub const MAX_SUPPLY: u128 = 100_000_000;    // 100 millió maximális supply
     pub const BASE_REWARD: u128 = 50;            // Alap blokk jutalom
     pub const BLOCKS_PER_YEAR: u32 = 2_628_000;  // ~6 másodperces blokkidővel
 
+    /// NPoS-style piecewise inflation curve, parameterized by the staking ratio.
+    ///
+    /// Below `x_ideal`, inflation rises linearly from `i_0` to `i_ideal`; above it,
+    /// inflation decays exponentially toward `i_0` with half-life `d`, discouraging
+    /// over-staking. Mirrors `pallet_staking`'s `PiecewiseLinear` reward curve.
+    pub trait RewardCurve {
+        /// Minimum inflation, paid even at zero stake.
+        fn min_inflation() -> Permill;
+        /// Inflation at the ideal staking ratio.
+        fn ideal_inflation() -> Permill;
+        /// Ideal staking ratio `x_ideal`, as a fraction of total issuance.
+        fn ideal_stake() -> Permill;
+        /// Falloff `d` controlling how fast inflation decays past `x_ideal`.
+        fn falloff() -> Permill;
+
+        /// Compute `I(x)` for a staking ratio `x` (both expressed in `Permill`).
+        fn annual_inflation(staked_ratio: Permill) -> Permill {
+            let i_0 = Self::min_inflation();
+            let i_ideal = Self::ideal_inflation();
+            let x_ideal = Self::ideal_stake();
+
+            if staked_ratio <= x_ideal {
+                // I(x) = i_0 + x * (i_ideal - i_0) / x_ideal
+                let slope = i_ideal.saturating_sub(i_0);
+                let progress = Permill::from_rational(staked_ratio.deconstruct(), x_ideal.deconstruct().max(1));
+                i_0.saturating_add(progress * slope)
+            } else {
+                // I(x) = i_0 + (i_ideal - i_0) * 2^((x_ideal - x)/d)
+                let d = Self::falloff();
+                let over = staked_ratio.saturating_sub(x_ideal);
+                // 2^((x_ideal - x)/d) approximated via halving: one halving per whole unit
+                // of `d` overshoot is the discrete analogue used here.
+                let halvings = over.deconstruct() / d.deconstruct().max(1);
+                let mut decayed = i_ideal.saturating_sub(i_0);
+                for _ in 0..halvings.min(20) {
+                    decayed = Permill::from_parts(decayed.deconstruct() / 2);
+                }
+                i_0.saturating_add(decayed)
+            }
+        }
+    }
+
     #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
     pub struct License<BlockNumber> {
         pub active: bool,
@@ -44,6 +92,21 @@ pub mod pallet {
         }
     }
 
+    /// KYC/AML verification status of an account, gating access to higher license
+    /// tiers without touching the reward math.
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, RuntimeDebug, TypeInfo)]
+    pub enum VerificationStatus {
+        Unverified,
+        Pending,
+        Verified,
+    }
+
+    impl Default for VerificationStatus {
+        fn default() -> Self {
+            Self::Unverified
+        }
+    }
+
     impl LicenseType {
         fn get_boost(&self) -> Permill {
             match self {
@@ -61,6 +124,15 @@ pub mod pallet {
                 LicenseType::Enterprise => 100_000u128.into(), // 100,000 HOM
             }
         }
+
+        /// Minimum KYC/AML status required to purchase this tier. Standard stays
+        /// open; Premium/Enterprise are restricted to verified participants.
+        fn required_status(&self) -> VerificationStatus {
+            match self {
+                LicenseType::Standard => VerificationStatus::Unverified,
+                LicenseType::Premium | LicenseType::Enterprise => VerificationStatus::Verified,
+            }
+        }
     }
 
     #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
@@ -71,8 +143,33 @@ pub mod pallet {
         pub utolsó_claim: BlockNumber,
     }
 
+    /// Default `OnUnbalanced` handler for license fees: resolves the configured
+    /// treasury share into the treasury account and the remainder to the current
+    /// block author, mirroring the standard `DealWithFees`/`ToAuthor` split.
+    pub struct SplitToTreasuryAndAuthor<T>(PhantomData<T>);
+
+    impl<T: Config> OnUnbalanced<NegativeImbalanceOf<T>> for SplitToTreasuryAndAuthor<T> {
+        fn on_nonzero_unbalanced(amount: NegativeImbalanceOf<T>) {
+            let treasury_cut = T::LicenseFeeTreasuryShare::get() * amount.peek();
+            let (treasury_part, author_part) = amount.split(treasury_cut);
+            let treasury_amount = treasury_part.peek();
+            let author_amount = author_part.peek();
+
+            T::Currency::resolve_creating(&Pallet::<T>::treasury_account_id(), treasury_part);
+            Pallet::<T>::deposit_event(Event::TreasuryFeeCollected { amount: treasury_amount });
+
+            if let Some(author) = pallet_authorship::Pallet::<T>::author() {
+                T::Currency::resolve_creating(&author, author_part);
+                Pallet::<T>::deposit_event(Event::AuthorFeeCollected { author, amount: author_amount });
+            } else {
+                T::Currency::resolve_creating(&Pallet::<T>::treasury_account_id(), author_part);
+                Pallet::<T>::deposit_event(Event::TreasuryFeeCollected { amount: author_amount });
+            }
+        }
+    }
+
     #[pallet::config]
-    pub trait Config: frame_system::Config + pallet_halom_oracle::Config {
+    pub trait Config: frame_system::Config + pallet_halom_oracle::Config + pallet_authorship::Config {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
         
         type Currency: Currency<Self::AccountId> + ReservableCurrency<Self::AccountId>;
@@ -83,6 +180,15 @@ pub mod pallet {
         #[pallet::constant]
         type TreasuryFeePercent: Get<Permill>;
 
+        /// Handler receiving the license purchase fee as a negative imbalance, to
+        /// be split between the treasury and the current block author.
+        type OnLicenseFee: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+        /// Share of the license fee routed to the treasury; the remainder goes to
+        /// the block author.
+        #[pallet::constant]
+        type LicenseFeeTreasuryShare: Get<Permill>;
+
         #[pallet::constant]
         type LicenseDuration: Get<Self::BlockNumber>;
 
@@ -92,6 +198,50 @@ pub mod pallet {
 
         #[pallet::constant]
         type StakingBonus: Get<Permill>;
+
+        /// Parameters of the NPoS-style inflation curve used to derive the per-block
+        /// base reward from the current staking ratio.
+        type RewardCurveParameters: RewardCurve;
+
+        #[pallet::constant]
+        type BlocksPerYear: Get<u32>;
+
+        /// The origin allowed to report miner misbehavior.
+        type SlashOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// The origin allowed to clear a miner's accumulated strikes.
+        type GovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Fraction of a miner's reserved stake slashed per reported misbehavior.
+        #[pallet::constant]
+        type SlashFraction: Get<Permill>;
+
+        /// Number of strikes after which a miner is automatically force-exited.
+        #[pallet::constant]
+        type MaxStrikes: Get<u32>;
+
+        /// When `true`, `issue_reward` mints the full reward immediately as before.
+        /// When `false`, the reward is locked under a `RewardVesting` schedule instead.
+        #[pallet::constant]
+        type ImmediateRewardPayout: Get<bool>;
+
+        /// Number of blocks a vested reward is spread across when immediate payout
+        /// is disabled.
+        #[pallet::constant]
+        type VestingDuration: Get<Self::BlockNumber>;
+
+        /// Absolute lower bound accepted for the oracle's `CurrentHOI` value.
+        #[pallet::constant]
+        type MinHOI: Get<u32>;
+
+        /// Absolute upper bound accepted for the oracle's `CurrentHOI` value.
+        #[pallet::constant]
+        type MaxHOI: Get<u32>;
+
+        /// Maximum relative change accepted between consecutive HOI readings;
+        /// larger swings are clamped to the nearest allowed bound.
+        #[pallet::constant]
+        type MaxHOIVariation: Get<Permill>;
     }
 
     #[pallet::pallet]
@@ -111,6 +261,12 @@ pub mod pallet {
     #[pallet::getter(fn total_issuance)]
     pub type TotalIssuance<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
 
+    /// Aggregate amount currently reserved via `stake_tokens`, kept up to date so the
+    /// staking ratio `x = total_staked / total_issuance` is cheap to read on every reward.
+    #[pallet::storage]
+    #[pallet::getter(fn total_staked)]
+    pub type TotalStaked<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
     #[pallet::storage]
     pub type LicenseStakes<T: Config> = StorageMap<
         _,
@@ -120,6 +276,76 @@ pub mod pallet {
         OptionQuery
     >;
 
+    /// Accumulated strikes and total slashed amount for a miner caught submitting
+    /// invalid work, used to escalate to a forced exit once `MaxStrikes` is hit.
+    #[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct PunishmentRecord<Balance> {
+        pub strikes: u32,
+        pub total_slashed: Balance,
+    }
+
+    /// A linear vesting schedule for a reward locked instead of paid out immediately.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct VestingSchedule<Balance, BlockNumber> {
+        pub start: BlockNumber,
+        pub per_block: Balance,
+        pub locked: Balance,
+        pub claimed: Balance,
+        pub last_claim: BlockNumber,
+    }
+
+    /// Per-account vesting schedule for rewards minted while immediate payout is
+    /// disabled; drained entries are removed once fully claimed.
+    #[pallet::storage]
+    #[pallet::getter(fn reward_vesting)]
+    pub type RewardVesting<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        VestingSchedule<BalanceOf<T>, T::BlockNumber>,
+        OptionQuery,
+    >;
+
+    /// Last HOI value accepted into reward calculation, used as the baseline for
+    /// the per-update deviation guard.
+    #[pallet::storage]
+    #[pallet::getter(fn last_accepted_hoi)]
+    pub type LastAcceptedHOI<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Block at which `LastAcceptedHOI` was last updated.
+    #[pallet::storage]
+    #[pallet::getter(fn last_hoi_update_block)]
+    pub type LastHOIUpdateBlock<T: Config> = StorageValue<_, T::BlockNumber, ValueQuery>;
+
+    /// Per-account monotonic sequence, bumped on every `issue_reward`, that lets a
+    /// batched caller detect that no intervening transaction touched their reward
+    /// state since they last observed it.
+    #[pallet::storage]
+    #[pallet::getter(fn reward_sequence)]
+    pub type RewardSequence<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
+    /// KYC/AML verification status per account, gating Premium/Enterprise license
+    /// purchases.
+    #[pallet::storage]
+    #[pallet::getter(fn verification_status)]
+    pub type VerificationStatuses<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        VerificationStatus,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn punishments)]
+    pub type Punishments<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        PunishmentRecord<BalanceOf<T>>,
+        ValueQuery,
+    >;
+
     #[pallet::storage]
     #[pallet::getter(fn staking_info)]
     pub type StakingInfo<T: Config> = StorageMap<
@@ -139,8 +365,24 @@ pub mod pallet {
         LicenseUpdated { account: T::AccountId, license_type: LicenseType, expiry: T::BlockNumber },
         /// Treasury fee collected. [amount]
         TreasuryFeeCollected { amount: BalanceOf<T> },
+        /// Block author's share of a license fee collected. [author, amount]
+        AuthorFeeCollected { author: T::AccountId, amount: BalanceOf<T> },
         TokensStaked { account: T::AccountId, amount: BalanceOf<T> },
         TokensUnstaked { account: T::AccountId, amount: BalanceOf<T> },
+        /// Miner slashed for reported misbehavior. [miner, slashed, strikes]
+        MinerPunished { miner: T::AccountId, slashed: BalanceOf<T>, strikes: u32 },
+        /// Miner force-exited after reaching the strike threshold. [miner]
+        MinerForceExited { miner: T::AccountId },
+        /// A miner's strikes were reset by governance. [miner]
+        PunishmentCleared { miner: T::AccountId },
+        /// Reward minted under a vesting schedule instead of paid out immediately.
+        RewardVested { miner: T::AccountId, amount: BalanceOf<T> },
+        /// Previously vested reward unlocked for free transfer. [account, amount]
+        VestedRewardClaimed { account: T::AccountId, amount: BalanceOf<T> },
+        /// A raw HOI reading was clamped before being used in reward calculation.
+        OracleValueClamped { raw: u32, clamped: u32 },
+        /// An account's KYC/AML verification status changed.
+        VerificationStatusChanged { account: T::AccountId, status: VerificationStatus },
     }
 
     #[pallet::error]
@@ -155,6 +397,16 @@ pub mod pallet {
         LicenseAlreadyActive,
         InsufficientStake,
         NoStake,
+        /// Miner has no stake left to slash.
+        NothingToSlash,
+        /// Account has no vesting schedule to claim from.
+        NoVestingSchedule,
+        /// Vesting schedule has nothing newly unlocked yet.
+        NothingVestedYet,
+        /// The caller's expected on-chain state does not match reality.
+        StateMismatch,
+        /// Caller's verification status does not meet the tier's requirement.
+        NotVerified,
     }
 
     #[pallet::call]
@@ -169,16 +421,22 @@ pub mod pallet {
             let current_license = Self::licenses(&who);
             ensure!(!current_license.active, Error::<T>::LicenseAlreadyActive);
 
+            ensure!(
+                Self::verification_status(&who) >= license_type.required_status(),
+                Error::<T>::NotVerified
+            );
+
             let price = license_type.get_price::<T>();
             let fee = T::TreasuryFeePercent::get() * price;
             let total_cost = price.saturating_add(fee);
 
-            T::Currency::transfer(
+            let imbalance = T::Currency::withdraw(
                 &who,
-                &Self::treasury_account_id(),
                 fee,
+                WithdrawReasons::TRANSFER,
                 ExistenceRequirement::KeepAlive,
             )?;
+            T::OnLicenseFee::on_unbalanced(imbalance);
 
             let expiry = frame_system::Pallet::<T>::block_number()
                 .saturating_add(T::LicenseDuration::get());
@@ -195,8 +453,6 @@ pub mod pallet {
                 expiry,
             });
 
-            Self::deposit_event(Event::TreasuryFeeCollected { amount: fee });
-
             Ok(())
         }
 
@@ -208,15 +464,80 @@ pub mod pallet {
             ensure_signed(origin)?;
 
             let reward = Self::calculate_reward(&miner)?;
-            
+
             let new_total = Self::total_issuance().saturating_add(reward);
             ensure!(new_total <= MAX_SUPPLY.into(), Error::<T>::SupplyCapReached);
-            
-            T::Currency::deposit_creating(&miner, reward);
             <TotalIssuance<T>>::put(new_total);
-            
-            Self::deposit_event(Event::RewardIssued { miner: miner.clone(), amount: reward });
-            
+
+            <RewardSequence<T>>::mutate(&miner, |seq| *seq = seq.saturating_add(1));
+
+            if T::ImmediateRewardPayout::get() {
+                T::Currency::deposit_creating(&miner, reward);
+                Self::deposit_event(Event::RewardIssued { miner, amount: reward });
+            } else {
+                Self::lock_under_vesting(&miner, reward);
+                Self::deposit_event(Event::RewardVested { miner, amount: reward });
+            }
+
+            Ok(())
+        }
+
+        /// Abort with `StateMismatch` unless the caller's expected view of reward
+        /// state still holds. Intended to be placed at the front of a
+        /// `utility.batch_all` so a stale view aborts the whole batch rather than
+        /// executing against state that shifted mid-block.
+        #[pallet::weight(10_000)]
+        pub fn assert_state(
+            origin: OriginFor<T>,
+            expected_hoi: Option<u32>,
+            expected_total_issuance: Option<BalanceOf<T>>,
+            expected_sequence: Option<u64>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            if let Some(expected) = expected_hoi {
+                ensure!(<CurrentHOI<T>>::get() == expected, Error::<T>::StateMismatch);
+            }
+
+            if let Some(expected) = expected_total_issuance {
+                ensure!(Self::total_issuance() == expected, Error::<T>::StateMismatch);
+            }
+
+            if let Some(expected) = expected_sequence {
+                ensure!(Self::reward_sequence(&who) == expected, Error::<T>::StateMismatch);
+            }
+
+            Ok(())
+        }
+
+        /// Release the portion of a reward vesting schedule unlocked since the last
+        /// claim, crediting it as free balance.
+        #[pallet::weight(10_000)]
+        pub fn claim_vested(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let mut schedule = <RewardVesting<T>>::get(&who).ok_or(Error::<T>::NoVestingSchedule)?;
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let elapsed = now.saturating_sub(schedule.last_claim);
+            let elapsed_blocks: u32 = elapsed.try_into().unwrap_or(u32::MAX);
+            let unlockable = schedule.per_block.saturating_mul(elapsed_blocks.into());
+            let unlockable = unlockable.min(schedule.locked.saturating_sub(schedule.claimed));
+
+            ensure!(unlockable > Zero::zero(), Error::<T>::NothingVestedYet);
+
+            T::Currency::deposit_creating(&who, unlockable);
+            schedule.claimed = schedule.claimed.saturating_add(unlockable);
+            schedule.last_claim = now;
+
+            if schedule.claimed >= schedule.locked {
+                <RewardVesting<T>>::remove(&who);
+            } else {
+                <RewardVesting<T>>::insert(&who, schedule);
+            }
+
+            Self::deposit_event(Event::VestedRewardClaimed { account: who, amount: unlockable });
+
             Ok(())
         }
 
@@ -231,38 +552,109 @@ pub mod pallet {
             
             T::Currency::reserve(&who, amount)?;
             <StakingInfo<T>>::insert(&who, amount);
-            
+            <TotalStaked<T>>::mutate(|total| *total = total.saturating_add(amount));
+
             Self::deposit_event(Event::TokensStaked { account: who, amount });
-            
+
             Ok(())
         }
 
         #[pallet::weight(10_000)]
         pub fn unstake_tokens(origin: OriginFor<T>) -> DispatchResult {
             let who = ensure_signed(origin)?;
-            
+
             let staked = Self::staking_info(&who);
             ensure!(staked > Zero::zero(), Error::<T>::NoStake);
-            
+
             T::Currency::unreserve(&who, staked);
             <StakingInfo<T>>::remove(&who);
-            
+            <TotalStaked<T>>::mutate(|total| *total = total.saturating_sub(staked));
+
             Self::deposit_event(Event::TokensUnstaked { account: who, amount: staked });
-            
+
+            Ok(())
+        }
+
+        /// Report a miner for submitting invalid work, slashing a configured fraction
+        /// of their reserved stake and escalating to a forced exit at `MaxStrikes`.
+        #[pallet::weight(10_000)]
+        pub fn report_misbehavior(
+            origin: OriginFor<T>,
+            miner: T::AccountId,
+        ) -> DispatchResult {
+            T::SlashOrigin::ensure_origin(origin)?;
+
+            let staked = Self::staking_info(&miner);
+            ensure!(staked > Zero::zero(), Error::<T>::NothingToSlash);
+
+            let slash_amount = T::SlashFraction::get() * staked;
+            let (imbalance, _remainder) = T::Currency::slash_reserved(&miner, slash_amount);
+            let slashed = imbalance.peek();
+            T::Currency::resolve_creating(&Self::treasury_account_id(), imbalance);
+
+            <StakingInfo<T>>::mutate(&miner, |s| *s = s.saturating_sub(slashed));
+            <TotalStaked<T>>::mutate(|total| *total = total.saturating_sub(slashed));
+
+            let record = <Punishments<T>>::mutate(&miner, |record| {
+                record.strikes = record.strikes.saturating_add(1);
+                record.total_slashed = record.total_slashed.saturating_add(slashed);
+                record.clone()
+            });
+
+            Self::deposit_event(Event::MinerPunished {
+                miner: miner.clone(),
+                slashed,
+                strikes: record.strikes,
+            });
+
+            if record.strikes >= T::MaxStrikes::get() {
+                Self::force_exit(&miner);
+                Self::deposit_event(Event::MinerForceExited { miner });
+            }
+
+            Ok(())
+        }
+
+        /// Reset a miner's strike count after a dispute is resolved in their favor.
+        #[pallet::weight(10_000)]
+        pub fn clear_punish(
+            origin: OriginFor<T>,
+            miner: T::AccountId,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            <Punishments<T>>::remove(&miner);
+
+            Self::deposit_event(Event::PunishmentCleared { miner });
+
+            Ok(())
+        }
+
+        /// Set an account's KYC/AML verification status, gating access to
+        /// Premium/Enterprise license tiers.
+        #[pallet::weight(10_000)]
+        pub fn set_verification_status(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+            status: VerificationStatus,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            <VerificationStatuses<T>>::insert(&account, status);
+
+            Self::deposit_event(Event::VerificationStatusChanged { account, status });
+
             Ok(())
         }
     }
 
     impl<T: Config> Pallet<T> {
-        fn calculate_reward(
-            miner: &T::AccountId,
-            blocks_since_start: T::BlockNumber,
-        ) -> Result<BalanceOf<T>, Error<T>> {
-            let base_reward = BASE_REWARD.into();
-            
+        fn calculate_reward(miner: &T::AccountId) -> Result<BalanceOf<T>, Error<T>> {
+            let base_reward = Self::per_block_base_reward();
+
             // Inflációs korrekció
-            let hoi = <CurrentHOI<T>>::get();
-            let inflation_bonus = Permill::from_parts(hoi as u32);
+            let hoi = Self::sanitized_hoi();
+            let inflation_bonus = Permill::from_parts(hoi);
             let reward_with_inflation = base_reward.saturating_mul(inflation_bonus);
             
             // Licenc bónusz
@@ -302,5 +694,95 @@ pub mod pallet {
         fn treasury_account_id() -> T::AccountId {
             T::TreasuryPalletId::get().into_account_truncating()
         }
+
+        /// Start (or extend) a miner's vesting schedule with a newly computed reward,
+        /// spreading it evenly over `VestingDuration` blocks.
+        fn lock_under_vesting(miner: &T::AccountId, amount: BalanceOf<T>) {
+            let now = frame_system::Pallet::<T>::block_number();
+            let duration: u32 = T::VestingDuration::get().try_into().unwrap_or(1).max(1);
+
+            <RewardVesting<T>>::mutate(miner, |maybe_schedule| {
+                if let Some(schedule) = maybe_schedule {
+                    let remaining = schedule.locked.saturating_sub(schedule.claimed).saturating_add(amount);
+                    schedule.locked = schedule.claimed.saturating_add(remaining);
+                    schedule.per_block = remaining / duration.into();
+                    // `per_block` was just recomputed from the combined remaining
+                    // amount, so the curve effectively restarts here: advance
+                    // `last_claim` (and `start`) to `now`, or blocks that already
+                    // elapsed under the old, smaller `per_block` would unlock at
+                    // the new, larger rate and front-load the newest reward onto
+                    // already-elapsed time.
+                    schedule.start = now;
+                    schedule.last_claim = now;
+                } else {
+                    *maybe_schedule = Some(VestingSchedule {
+                        start: now,
+                        per_block: amount / duration.into(),
+                        locked: amount,
+                        claimed: Zero::zero(),
+                        last_claim: now,
+                    });
+                }
+            });
+        }
+
+        /// Deactivate a miner's license and unreserve/remove whatever stake remains,
+        /// following the final strike that crosses `MaxStrikes`.
+        fn force_exit(miner: &T::AccountId) {
+            <Licenses<T>>::mutate(miner, |license| license.active = false);
+
+            let remaining = Self::staking_info(miner);
+            if remaining > Zero::zero() {
+                T::Currency::unreserve(miner, remaining);
+                <TotalStaked<T>>::mutate(|total| *total = total.saturating_sub(remaining));
+            }
+            <StakingInfo<T>>::remove(miner);
+        }
+
+        /// Current staking ratio `x = total_staked / total_issuance`; zero while
+        /// nothing has been issued.
+        fn staking_ratio() -> Permill {
+            let issuance = Self::total_issuance();
+            if issuance.is_zero() {
+                return Permill::zero();
+            }
+            let staked = Self::total_staked();
+            Permill::from_rational(staked, issuance)
+        }
+
+        /// Read `CurrentHOI` guarded against oracle error: clamp to the absolute
+        /// `[MinHOI, MaxHOI]` range, then clamp again if it deviates from the last
+        /// accepted value by more than `MaxHOIVariation`. Emits `OracleValueClamped`
+        /// whenever the raw reading is adjusted, and records the accepted value.
+        fn sanitized_hoi() -> u32 {
+            let raw = <CurrentHOI<T>>::get();
+            let mut clamped = raw.clamp(T::MinHOI::get(), T::MaxHOI::get());
+
+            let last = Self::last_accepted_hoi();
+            if last > 0 {
+                let max_delta = T::MaxHOIVariation::get() * last;
+                let upper = last.saturating_add(max_delta);
+                let lower = last.saturating_sub(max_delta);
+                clamped = clamped.clamp(lower, upper);
+            }
+
+            if clamped != raw {
+                Self::deposit_event(Event::OracleValueClamped { raw, clamped });
+            }
+
+            <LastAcceptedHOI<T>>::put(clamped);
+            <LastHOIUpdateBlock<T>>::put(frame_system::Pallet::<T>::block_number());
+
+            clamped
+        }
+
+        /// Derive the per-block base reward from the NPoS inflation curve: annual
+        /// inflation `I(x)` at the current staking ratio, times total issuance,
+        /// spread evenly over `BlocksPerYear` blocks.
+        fn per_block_base_reward() -> BalanceOf<T> {
+            let annual_inflation = T::RewardCurveParameters::annual_inflation(Self::staking_ratio());
+            let annual_issuance = annual_inflation * Self::total_issuance();
+            annual_issuance / T::BlocksPerYear::get().into()
+        }
     }
 } 
\ No newline at end of file
@@ -1,30 +1,36 @@
 use crate as pallet_pow_rewards;
 use frame_support::{
     parameter_types,
-    traits::{ConstU16, ConstU64},
+    traits::{ConstU32, ConstU64, FindAuthor, Hooks},
+    weights::Weight,
     PalletId,
 };
-use frame_system as system;
-use sp_core::H256;
+use frame_system::EnsureRoot;
+use pallet_halom_oracle::MedianAbsoluteDeviation;
+use sp_core::{Pair, H256};
 use sp_runtime::{
-    traits::{BlakeTwo256, IdentityLookup},
-    BuildStorage, Permill,
+    testing::TestXt,
+    traits::{BlakeTwo256, IdentifyAccount, IdentityLookup, Verify},
+    BuildStorage, ConsensusEngineId, MultiSignature, Permill,
 };
-use pallet_halom_oracle;
 
 type Block = frame_system::mocking::MockBlock<Test>;
+type Balance = u128;
+type Signature = MultiSignature;
+type AccountId = <<Signature as Verify>::Signer as IdentifyAccount>::AccountId;
+type Extrinsic = TestXt<RuntimeCall, ()>;
 
-// Configure a mock runtime to test the pallet.
 frame_support::construct_runtime!(
     pub enum Test {
         System: frame_system,
         Balances: pallet_balances,
+        Authorship: pallet_authorship,
         HalomOracle: pallet_halom_oracle,
         PowRewards: pallet_pow_rewards,
     }
 );
 
-impl system::Config for Test {
+impl frame_system::Config for Test {
     type BaseCallFilter = frame_support::traits::Everything;
     type BlockWeights = ();
     type BlockLength = ();
@@ -34,30 +40,34 @@ impl system::Config for Test {
     type Nonce = u64;
     type Hash = H256;
     type Hashing = BlakeTwo256;
-    type AccountId = u64;
+    type AccountId = AccountId;
     type Lookup = IdentityLookup<Self::AccountId>;
     type Block = Block;
     type RuntimeEvent = RuntimeEvent;
     type BlockHashCount = ConstU64<250>;
     type Version = ();
     type PalletInfo = PalletInfo;
-    type AccountData = pallet_balances::AccountData<u64>;
+    type AccountData = pallet_balances::AccountData<Balance>;
     type OnNewAccount = ();
     type OnKilledAccount = ();
     type SystemWeightInfo = ();
-    type SS58Prefix = ConstU16<42>;
+    type SS58Prefix = ();
     type OnSetCode = ();
-    type MaxConsumers = frame_support::traits::ConstU32<16>;
+    type MaxConsumers = ConstU32<16>;
+}
+
+parameter_types! {
+    pub const ExistentialDeposit: Balance = 1;
 }
 
 impl pallet_balances::Config for Test {
-    type MaxLocks = ();
-    type MaxReserves = ();
+    type MaxLocks = ConstU32<50>;
+    type MaxReserves = ConstU32<50>;
     type ReserveIdentifier = [u8; 8];
-    type Balance = u64;
+    type Balance = Balance;
     type RuntimeEvent = RuntimeEvent;
     type DustRemoval = ();
-    type ExistentialDeposit = ConstU64<1>;
+    type ExistentialDeposit = ExistentialDeposit;
     type AccountStore = System;
     type WeightInfo = ();
     type FreezeIdentifier = ();
@@ -66,52 +76,231 @@ impl pallet_balances::Config for Test {
     type MaxHolds = ();
 }
 
+/// `SplitToTreasuryAndAuthor` pays the non-treasury share of a license fee to
+/// the current block author, so the mock needs a `FindAuthor` that actually
+/// resolves to someone rather than `()`'s permanent `None`.
+pub struct AuthorGiven;
+impl FindAuthor<AccountId> for AuthorGiven {
+    fn find_author<'a, I>(_digests: I) -> Option<AccountId>
+    where
+        I: 'a + IntoIterator<Item = (ConsensusEngineId, &'a [u8])>,
+    {
+        Some(author())
+    }
+}
+
+impl pallet_authorship::Config for Test {
+    type FindAuthor = AuthorGiven;
+    type EventHandler = ();
+}
+
+// Offchain-worker signed-transaction plumbing, required transitively through
+// `pallet_pow_rewards::Config: pallet_halom_oracle::Config`; see
+// `pallet_halom_oracle`'s own mock for the rationale.
+impl frame_system::offchain::SigningTypes for Test {
+    type Public = <Signature as Verify>::Signer;
+    type Signature = Signature;
+}
+
+impl<LocalCall> frame_system::offchain::SendTransactionTypes<LocalCall> for Test
+where
+    RuntimeCall: From<LocalCall>,
+{
+    type OverarchingCall = RuntimeCall;
+    type Extrinsic = Extrinsic;
+}
+
+impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Test
+where
+    RuntimeCall: From<LocalCall>,
+{
+    fn create_transaction<C: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>>(
+        call: RuntimeCall,
+        _public: <Signature as Verify>::Signer,
+        _account: AccountId,
+        nonce: u64,
+    ) -> Option<(RuntimeCall, <Extrinsic as sp_runtime::traits::Extrinsic>::SignaturePayload)> {
+        Some((call, (nonce, ())))
+    }
+}
+
+parameter_types! {
+    pub const MinUpdateInterval: u64 = 10;
+    pub const MaxUpdateInterval: u64 = 100;
+    pub const MinSourcesForConsensus: u32 = 2;
+    pub const VotingPeriod: u64 = 50;
+    pub const RequiredMajority: u32 = 66;
+    pub const OutlierDeviationFactor: u32 = 3;
+    pub const MaxObservationAge: u64 = 20;
+    pub const CouncilBond: Balance = 100;
+    pub const LivenessPenalty: Permill = Permill::from_percent(10);
+    pub const MaxMissedHeartbeats: u32 = 3;
+    pub const ConvictionVoteLockPeriod: u64 = 10;
+    pub const MaxAgendaItemsPerBlock: u32 = 10;
+    pub const MaxProposalLen: u32 = 1_024;
+    pub const MaxProposalWeight: Weight = Weight::from_parts(1_000_000_000, 0);
+    pub const MinQuorum: Permill = Permill::from_percent(50);
+    pub const MaxReputation: u32 = 200;
+    pub const MinReputation: u32 = 10;
+    pub const ReputationStepSize: u32 = 5;
+    pub const ReputationTolerance: Permill = Permill::from_percent(5);
+    pub const StalenessWindow: u64 = 20;
+    pub const MaxCouncilMembers: u32 = 10;
+    pub const MinVotingDuration: u64 = 5;
+    // Matches the runtime: submit_source_value/submit_signed_source_value
+    // (try_consensus) is canonical, so submit_observation stays disabled.
+    pub const ObservationConsensusEnabled: bool = false;
+}
+
 impl pallet_halom_oracle::Config for Test {
     type RuntimeEvent = RuntimeEvent;
-    type OracleUpdateInterval = ConstU64<10>;
+    type AuthorityId = pallet_halom_oracle::crypto::OracleAuthId;
+    type Aggregator = MedianAbsoluteDeviation<OutlierDeviationFactor>;
+    type OutlierDeviationFactor = OutlierDeviationFactor;
+    type MaxObservationAge = MaxObservationAge;
+    type ObservationConsensusEnabled = ObservationConsensusEnabled;
+    type OracleUpdateOrigin = EnsureRoot<AccountId>;
+    type GovernanceOrigin = EnsureRoot<AccountId>;
+    type MembershipOrigin = EnsureRoot<AccountId>;
+    type VotingPeriod = VotingPeriod;
+    type MinUpdateInterval = MinUpdateInterval;
+    type MaxUpdateInterval = MaxUpdateInterval;
+    type MinSourcesForConsensus = MinSourcesForConsensus;
+    type RequiredMajority = RequiredMajority;
+    type Currency = Balances;
+    type CouncilBond = CouncilBond;
+    type LivenessPenalty = LivenessPenalty;
+    type MaxMissedHeartbeats = MaxMissedHeartbeats;
+    type ConvictionVoteLockPeriod = ConvictionVoteLockPeriod;
+    type MaxAgendaItemsPerBlock = MaxAgendaItemsPerBlock;
+    type RuntimeCall = RuntimeCall;
+    type MaxProposalLen = MaxProposalLen;
+    type MaxProposalWeight = MaxProposalWeight;
+    type MinQuorum = MinQuorum;
+    type MaxReputation = MaxReputation;
+    type MinReputation = MinReputation;
+    type ReputationStepSize = ReputationStepSize;
+    type ReputationTolerance = ReputationTolerance;
+    type StalenessWindow = StalenessWindow;
+    type MaxCouncilMembers = MaxCouncilMembers;
+    type MinVotingDuration = MinVotingDuration;
+    type WeightInfo = ();
+}
+
+/// Concrete NPoS-style curve for the mock: 2% floor, 10% at 50% ideal stake,
+/// 5% falloff past that point. Values are picked for test legibility, not to
+/// mirror the runtime's production curve.
+pub struct TestRewardCurve;
+impl pallet_pow_rewards::RewardCurve for TestRewardCurve {
+    fn min_inflation() -> Permill {
+        Permill::from_percent(2)
+    }
+    fn ideal_inflation() -> Permill {
+        Permill::from_percent(10)
+    }
+    fn ideal_stake() -> Permill {
+        Permill::from_percent(50)
+    }
+    fn falloff() -> Permill {
+        Permill::from_percent(5)
+    }
 }
 
 parameter_types! {
-    pub const BaseReward: u64 = 1_000;
-    pub const MaxSupply: u64 = 21_000_000;
-    pub const StandardLicensePrice: u64 = 1_000;
-    pub const PremiumLicensePrice: u64 = 5_000;
-    pub const EnterpriseLicensePrice: u64 = 20_000;
-    pub const LicenseDuration: u64 = 100;
     pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
     pub const TreasuryFeePercent: Permill = Permill::from_percent(10);
+    pub const LicenseFeeTreasuryShare: Permill = Permill::from_percent(50);
+    pub const LicenseDuration: u64 = 100;
+    pub const MinimumStake: Balance = 1_000;
+    pub const StakingBonus: Permill = Permill::from_percent(5);
+    pub const BlocksPerYear: u32 = 100;
+    pub const SlashFraction: Permill = Permill::from_percent(10);
+    pub const MaxStrikes: u32 = 3;
+    pub const ImmediateRewardPayout: bool = false;
+    pub const VestingDuration: u64 = 10;
+    pub const MinHOI: u32 = 1;
+    pub const MaxHOI: u32 = 1_000_000;
+    pub const MaxHOIVariation: Permill = Permill::from_percent(20);
 }
 
 impl pallet_pow_rewards::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
-    type BaseReward = BaseReward;
-    type MaxSupply = MaxSupply;
-    type StandardLicensePrice = StandardLicensePrice;
-    type PremiumLicensePrice = PremiumLicensePrice;
-    type EnterpriseLicensePrice = EnterpriseLicensePrice;
-    type LicenseDuration = LicenseDuration;
     type TreasuryPalletId = TreasuryPalletId;
     type TreasuryFeePercent = TreasuryFeePercent;
+    type OnLicenseFee = pallet_pow_rewards::SplitToTreasuryAndAuthor<Test>;
+    type LicenseFeeTreasuryShare = LicenseFeeTreasuryShare;
+    type LicenseDuration = LicenseDuration;
+    type MinimumStake = MinimumStake;
+    type StakingBonus = StakingBonus;
+    type RewardCurveParameters = TestRewardCurve;
+    type BlocksPerYear = BlocksPerYear;
+    type SlashOrigin = EnsureRoot<AccountId>;
+    type GovernanceOrigin = EnsureRoot<AccountId>;
+    type SlashFraction = SlashFraction;
+    type MaxStrikes = MaxStrikes;
+    type ImmediateRewardPayout = ImmediateRewardPayout;
+    type VestingDuration = VestingDuration;
+    type MinHOI = MinHOI;
+    type MaxHOI = MaxHOI;
+    type MaxHOIVariation = MaxHOIVariation;
+}
+
+/// Well-known sr25519 test accounts, mirroring `pallet_halom_oracle`'s mock
+/// (`PowRewards::Config` requires `pallet_halom_oracle::Config`, which in turn
+/// requires real sr25519-derived `AccountId`s for its signed-payload checks).
+pub fn alice() -> AccountId {
+    sp_core::sr25519::Pair::from_string("//Alice", None)
+        .unwrap()
+        .public()
+        .into()
+}
+
+pub fn bob() -> AccountId {
+    sp_core::sr25519::Pair::from_string("//Bob", None)
+        .unwrap()
+        .public()
+        .into()
+}
+
+/// The account `AuthorGiven` always resolves as the current block's author.
+pub fn author() -> AccountId {
+    sp_core::sr25519::Pair::from_string("//Author", None)
+        .unwrap()
+        .public()
+        .into()
 }
 
-// Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-    let mut t = system::GenesisConfig::<Test>::default()
+    let mut t = frame_system::GenesisConfig::<Test>::default()
         .build_storage()
         .unwrap();
 
     pallet_balances::GenesisConfig::<Test> {
         balances: vec![
-            (1, 10_000_000),  // Treasury
-            (2, 10_000_000),  // Test account 1
-            (3, 10_000_000),  // Test account 2
+            (alice(), 10_000_000),
+            (bob(), 10_000_000),
+            (author(), 10_000_000),
         ],
     }
     .assimilate_storage(&mut t)
     .unwrap();
 
+    pallet_halom_oracle::GenesisConfig::<Test> {
+        initial_sources: vec![b"KSH".to_vec()],
+        initial_members: vec![alice()],
+        _phantom: Default::default(),
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
     let mut ext = sp_io::TestExternalities::new(t);
-    ext.execute_with(|| System::set_block_number(1));
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        // `AuthorGiven` ignores the actual digest and always resolves to
+        // `author()`, so a single on_initialize is enough to seed the
+        // `Author` storage `SplitToTreasuryAndAuthor` reads from.
+        Authorship::on_initialize(1);
+    });
     ext
-} 
\ No newline at end of file
+}
@@ -1,292 +1,188 @@
-use crate::{mock::*, Error, Event, License, LicenseType};
+#![cfg(test)]
+
+use crate::{mock::*, Error, Event, LicenseType, VerificationStatus};
 use frame_support::{assert_noop, assert_ok};
-use sp_runtime::traits::BadOrigin;
 use sp_runtime::traits::AccountIdConversion;
 
-#[test]
-fn test_set_license_status() {
-    new_test_ext().execute_with(|| {
-        System::set_block_number(1);
-
-        // Only root can set license
-        assert_noop!(
-            PowRewards::set_license_status(RuntimeOrigin::signed(1), 1, true),
-            BadOrigin
-        );
-
-        // Root can set license
-        assert_ok!(PowRewards::set_license_status(RuntimeOrigin::root(), 1, true));
-
-        // Check event was emitted
-        System::assert_last_event(Event::LicenseStatusChanged {
-            account: 1,
-            status: true,
-        }.into());
-
-        // Check storage was updated
-        assert!(PowRewards::licenses(1));
-    });
+fn seed_issuance(amount: u128) {
+    crate::TotalIssuance::<Test>::put(amount);
 }
 
 #[test]
-fn test_issue_reward_basic() {
+fn reward_curve_derives_base_reward_from_staking_ratio() {
     new_test_ext().execute_with(|| {
-        System::set_block_number(1);
-        
-        // Set HOI to 105 (5% inflation)
-        assert_ok!(HalomOracle::submit_hoi_value(RuntimeOrigin::signed(1), 105));
-
-        // Issue reward without license
-        assert_ok!(PowRewards::issue_reward(RuntimeOrigin::signed(1), 1));
-
-        // Check balance was updated with base reward * inflation
-        let expected_reward = (BaseReward::get() as f64 * 1.05) as u128;
-        assert_eq!(Balances::free_balance(1), 10_000 + expected_reward);
+        // Zero staked against a 1,000,000-unit issuance puts the curve at its
+        // floor: 2% annual inflation spread over BlocksPerYear (100) blocks.
+        seed_issuance(1_000_000);
+        pallet_halom_oracle::CurrentHOI::<Test>::put(1_000_000); // 100%, no HOI adjustment
+
+        assert_ok!(PowRewards::issue_reward(RuntimeOrigin::signed(alice()), bob()));
+
+        // ImmediateRewardPayout is false in this mock, so the reward is locked
+        // under vesting rather than credited directly; read it back from there.
+        // annual_issuance = 2% * 1_000_000 = 20_000, spread over 100 blocks/year.
+        let schedule = PowRewards::reward_vesting(bob()).unwrap();
+        assert_eq!(schedule.locked, 200);
     });
 }
 
 #[test]
-fn test_issue_reward_with_license() {
+fn report_misbehavior_slashes_stake_and_escalates_to_force_exit() {
     new_test_ext().execute_with(|| {
-        System::set_block_number(1);
-        
-        // Set HOI to 105 (5% inflation)
-        assert_ok!(HalomOracle::submit_hoi_value(RuntimeOrigin::signed(1), 105));
+        assert_ok!(PowRewards::stake_tokens(RuntimeOrigin::signed(bob()), 1_000));
 
-        // Set license
-        assert_ok!(PowRewards::set_license_status(RuntimeOrigin::root(), 1, true));
-
-        // Issue reward with license
-        assert_ok!(PowRewards::issue_reward(RuntimeOrigin::signed(1), 1));
+        assert_noop!(
+            PowRewards::report_misbehavior(RuntimeOrigin::signed(alice()), bob()),
+            sp_runtime::DispatchError::BadOrigin,
+        );
 
-        // Check balance was updated with (base reward * inflation) * (1 + boost)
-        let base_with_inflation = (BaseReward::get() as f64 * 1.05) as u128;
-        let expected_reward = base_with_inflation + (base_with_inflation / 5); // 20% boost
-        assert_eq!(Balances::free_balance(1), 10_000 + expected_reward);
-    });
-}
+        // SlashFraction is 10%: three strikes slash 100, 90, 81 from the
+        // shrinking stake and reach MaxStrikes (3) on the third.
+        assert_ok!(PowRewards::report_misbehavior(RuntimeOrigin::root(), bob()));
+        assert_eq!(PowRewards::staking_info(bob()), 900);
 
-#[test]
-fn test_supply_cap() {
-    new_test_ext().execute_with(|| {
-        System::set_block_number(1);
+        assert_ok!(PowRewards::report_misbehavior(RuntimeOrigin::root(), bob()));
+        assert_ok!(PowRewards::report_misbehavior(RuntimeOrigin::root(), bob()));
 
-        // Set total issuance near max supply
-        let near_max = MaxSupply::get() - BaseReward::get();
-        PowRewards::set_total_issuance(near_max);
+        System::assert_has_event(Event::MinerForceExited { miner: bob() }.into());
+        assert_eq!(PowRewards::punishments(bob()).strikes, 3);
+        // A force exit unreserves and clears whatever stake remained.
+        assert_eq!(PowRewards::staking_info(bob()), 0);
 
-        // Try to issue reward that would exceed cap
-        assert_noop!(
-            PowRewards::issue_reward(RuntimeOrigin::signed(1), 1),
-            Error::<Test>::SupplyCapReached
-        );
+        assert_ok!(PowRewards::clear_punish(RuntimeOrigin::root(), bob()));
+        assert_eq!(PowRewards::punishments(bob()).strikes, 0);
     });
 }
 
 #[test]
-fn test_reward_calculation() {
+fn issue_reward_locks_under_vesting_and_claim_vested_unlocks_linearly() {
     new_test_ext().execute_with(|| {
-        System::set_block_number(1);
+        seed_issuance(1_000_000);
+        pallet_halom_oracle::CurrentHOI::<Test>::put(1_000_000);
 
-        // Test different HOI values
-        let test_cases = vec![
-            (100, BaseReward::get()),  // No inflation
-            (105, (BaseReward::get() as f64 * 1.05) as u128),  // 5% inflation
-            (110, (BaseReward::get() as f64 * 1.10) as u128),  // 10% inflation
-        ];
-
-        for (hoi, expected_base) in test_cases {
-            // Set HOI
-            assert_ok!(HalomOracle::submit_hoi_value(RuntimeOrigin::signed(1), hoi));
+        System::set_block_number(1);
+        assert_ok!(PowRewards::issue_reward(RuntimeOrigin::signed(alice()), bob()));
+        System::assert_last_event(Event::RewardVested { miner: bob(), amount: 200 }.into());
+
+        // VestingDuration is 10, so per_block is locked / 10 = 20.
+        System::set_block_number(4);
+        assert_ok!(PowRewards::claim_vested(RuntimeOrigin::signed(bob())));
+        assert_eq!(Balances::free_balance(bob()), 10_000_000 + 3 * 20);
+
+        // A second reward lands before the first schedule fully drains. The
+        // combined remaining amount restarts the curve from now (chunk0-3's
+        // fix): start/last_claim should advance to the current block rather
+        // than staying put, so claiming immediately afterwards sees nothing
+        // newly unlocked instead of front-loading elapsed time onto the
+        // larger, recomputed per_block rate.
+        System::set_block_number(5);
+        assert_ok!(PowRewards::issue_reward(RuntimeOrigin::signed(alice()), bob()));
+        let schedule = PowRewards::reward_vesting(bob()).unwrap();
+        assert_eq!(schedule.start, 5);
+        assert_eq!(schedule.last_claim, 5);
 
-            // Test without license
-            assert_ok!(PowRewards::issue_reward(RuntimeOrigin::signed(2), 2));
-            assert_eq!(
-                Balances::free_balance(2),
-                20_000 + expected_base
-            );
+        assert_noop!(
+            PowRewards::claim_vested(RuntimeOrigin::signed(bob())),
+            Error::<Test>::NothingVestedYet,
+        );
 
-            // Set license and test with boost
-            assert_ok!(PowRewards::set_license_status(RuntimeOrigin::root(), 2, true));
-            assert_ok!(PowRewards::issue_reward(RuntimeOrigin::signed(2), 2));
-            
-            let boosted_reward = expected_base + (expected_base / 5);  // 20% boost
-            assert_eq!(
-                Balances::free_balance(2),
-                20_000 + expected_base + boosted_reward
-            );
-        }
+        // A full VestingDuration later, the whole remaining balance is claimable.
+        System::set_block_number(15);
+        assert_ok!(PowRewards::claim_vested(RuntimeOrigin::signed(bob())));
+        assert!(PowRewards::reward_vesting(bob()).is_none());
     });
 }
 
 #[test]
-fn test_issue_reward_works() {
+fn sanitized_hoi_clamps_absolute_and_relative_bounds() {
     new_test_ext().execute_with(|| {
-        // Set HOI to 100 (1.0)
-        pallet_halom_oracle::CurrentHOI::<Test>::put(100);
-        
-        // Issue reward to account 2
-        assert_ok!(PowRewards::issue_reward(RuntimeOrigin::signed(1), 2));
-        
-        // Check reward was issued correctly
-        assert_eq!(Balances::free_balance(2), 11_000_000); // Initial 10M + 1000 reward
-        
-        // Check event was emitted
-        System::assert_last_event(Event::RewardIssued { 
-            miner: 2, 
-            amount: 1_000 
-        }.into());
+        seed_issuance(1_000_000);
+
+        // Above MaxHOI (1_000_000): clamped down, and since there's no prior
+        // accepted value yet, only the absolute bound applies.
+        pallet_halom_oracle::CurrentHOI::<Test>::put(2_000_000);
+        assert_ok!(PowRewards::issue_reward(RuntimeOrigin::signed(alice()), bob()));
+        System::assert_has_event(
+            Event::OracleValueClamped { raw: 2_000_000, clamped: 1_000_000 }.into(),
+        );
+        assert_eq!(PowRewards::last_accepted_hoi(), 1_000_000);
+
+        // A reading within the absolute bounds but swinging more than
+        // MaxHOIVariation (20%) away from the last accepted value is clamped
+        // to that relative bound instead.
+        pallet_halom_oracle::CurrentHOI::<Test>::put(500_000);
+        assert_ok!(PowRewards::issue_reward(RuntimeOrigin::signed(alice()), bob()));
+        let expected = 1_000_000 - (1_000_000 / 5); // last - 20%
+        System::assert_has_event(
+            Event::OracleValueClamped { raw: 500_000, clamped: expected }.into(),
+        );
+        assert_eq!(PowRewards::last_accepted_hoi(), expected);
     });
 }
 
 #[test]
-fn test_issue_reward_with_standard_license() {
+fn purchase_license_splits_fee_between_treasury_and_author() {
     new_test_ext().execute_with(|| {
-        // Set HOI to 100 (1.0)
-        pallet_halom_oracle::CurrentHOI::<Test>::put(100);
-        
-        // Purchase standard license for account 2
+        let treasury = TreasuryPalletId::get().into_account_truncating();
+        let treasury_before = Balances::free_balance(&treasury);
+        let author_before = Balances::free_balance(author());
+
+        // Standard price is 5_000, TreasuryFeePercent is 10% -> fee of 500,
+        // split 50/50 (LicenseFeeTreasuryShare) between treasury and author.
         assert_ok!(PowRewards::purchase_license(
-            RuntimeOrigin::signed(2),
+            RuntimeOrigin::signed(alice()),
             LicenseType::Standard
         ));
-        
-        // Issue reward
-        assert_ok!(PowRewards::issue_reward(RuntimeOrigin::signed(1), 2));
-        
-        // Check reward was boosted by 20%
-        // Base reward: 1000
-        // Standard boost: 20%
-        // Expected: 1200
-        assert_eq!(
-            Balances::free_balance(2),
-            10_000_000 - 1_000 - 100 + 1_200 // Initial - License price - Fee + Boosted reward
-        );
-    });
-}
 
-#[test]
-fn test_issue_reward_with_premium_license() {
-    new_test_ext().execute_with(|| {
-        // Set HOI to 100 (1.0)
-        pallet_halom_oracle::CurrentHOI::<Test>::put(100);
-        
-        // Purchase premium license for account 2
-        assert_ok!(PowRewards::purchase_license(
-            RuntimeOrigin::signed(2),
-            LicenseType::Premium
-        ));
-        
-        // Issue reward
-        assert_ok!(PowRewards::issue_reward(RuntimeOrigin::signed(1), 2));
-        
-        // Check reward was boosted by 35%
-        // Base reward: 1000
-        // Premium boost: 35%
-        // Expected: 1350
-        assert_eq!(
-            Balances::free_balance(2),
-            10_000_000 - 5_000 - 500 + 1_350 // Initial - License price - Fee + Boosted reward
-        );
+        assert_eq!(Balances::free_balance(&treasury), treasury_before + 250);
+        assert_eq!(Balances::free_balance(author()), author_before + 250);
+        System::assert_has_event(Event::TreasuryFeeCollected { amount: 250 }.into());
+        System::assert_has_event(Event::AuthorFeeCollected { author: author(), amount: 250 }.into());
     });
 }
 
 #[test]
-fn test_issue_reward_with_enterprise_license() {
+fn assert_state_aborts_on_stale_view() {
     new_test_ext().execute_with(|| {
-        // Set HOI to 100 (1.0)
-        pallet_halom_oracle::CurrentHOI::<Test>::put(100);
-        
-        // Purchase enterprise license for account 2
-        assert_ok!(PowRewards::purchase_license(
-            RuntimeOrigin::signed(2),
-            LicenseType::Enterprise
+        assert_ok!(PowRewards::assert_state(
+            RuntimeOrigin::signed(alice()),
+            Some(0),
+            Some(0),
+            Some(0),
         ));
-        
-        // Issue reward
-        assert_ok!(PowRewards::issue_reward(RuntimeOrigin::signed(1), 2));
-        
-        // Check reward was boosted by 50%
-        // Base reward: 1000
-        // Enterprise boost: 50%
-        // Expected: 1500
-        assert_eq!(
-            Balances::free_balance(2),
-            10_000_000 - 20_000 - 2_000 + 1_500 // Initial - License price - Fee + Boosted reward
-        );
-    });
-}
 
-#[test]
-fn test_license_expiry() {
-    new_test_ext().execute_with(|| {
-        // Set HOI to 100 (1.0)
-        pallet_halom_oracle::CurrentHOI::<Test>::put(100);
-        
-        // Purchase standard license for account 2
-        assert_ok!(PowRewards::purchase_license(
-            RuntimeOrigin::signed(2),
-            LicenseType::Standard
-        ));
-        
-        // Fast forward to just before expiry
-        System::set_block_number(99);
-        assert_ok!(PowRewards::issue_reward(RuntimeOrigin::signed(1), 2));
-        
-        // Should still get boosted reward
-        assert_eq!(
-            Balances::free_balance(2),
-            10_000_000 - 1_000 - 100 + 1_200 // Initial - License price - Fee + Boosted reward
-        );
-        
-        // Fast forward past expiry
-        System::set_block_number(101);
-        assert_ok!(PowRewards::issue_reward(RuntimeOrigin::signed(1), 2));
-        
-        // Should get normal reward
-        assert_eq!(
-            Balances::free_balance(2),
-            10_000_000 - 1_000 - 100 + 1_200 + 1_000 // Previous balance + Normal reward
+        seed_issuance(1_000_000);
+
+        assert_noop!(
+            PowRewards::assert_state(RuntimeOrigin::signed(alice()), None, Some(0), None),
+            Error::<Test>::StateMismatch,
         );
+        assert_ok!(PowRewards::assert_state(
+            RuntimeOrigin::signed(alice()),
+            None,
+            Some(1_000_000),
+            None,
+        ));
     });
 }
 
 #[test]
-fn test_license_already_active() {
+fn purchase_license_requires_verification_for_premium_tier() {
     new_test_ext().execute_with(|| {
-        // Purchase standard license
-        assert_ok!(PowRewards::purchase_license(
-            RuntimeOrigin::signed(2),
-            LicenseType::Standard
-        ));
-        
-        // Try to purchase another license
         assert_noop!(
-            PowRewards::purchase_license(RuntimeOrigin::signed(2), LicenseType::Premium),
-            Error::<Test>::LicenseAlreadyActive
+            PowRewards::purchase_license(RuntimeOrigin::signed(alice()), LicenseType::Premium),
+            Error::<Test>::NotVerified,
         );
-    });
-}
 
-#[test]
-fn test_treasury_fees() {
-    new_test_ext().execute_with(|| {
-        let treasury_account = PowRewards::treasury_account_id();
-        let initial_treasury_balance = Balances::free_balance(&treasury_account);
-        
-        // Purchase standard license
+        assert_ok!(PowRewards::set_verification_status(
+            RuntimeOrigin::root(),
+            alice(),
+            VerificationStatus::Verified,
+        ));
+
         assert_ok!(PowRewards::purchase_license(
-            RuntimeOrigin::signed(2),
-            LicenseType::Standard
+            RuntimeOrigin::signed(alice()),
+            LicenseType::Premium
         ));
-        
-        // Check treasury received 10% fee
-        assert_eq!(
-            Balances::free_balance(&treasury_account),
-            initial_treasury_balance + 100 // 10% of 1000
-        );
-        
-        // Check event was emitted
-        System::assert_has_event(Event::TreasuryFeeCollected { amount: 100 }.into());
     });
-} 
\ No newline at end of file
+}
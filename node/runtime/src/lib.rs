@@ -21,6 +21,8 @@ use sp_runtime::{
 };
 use sp_std::prelude::*;
 use sp_version::RuntimeVersion;
+use sp_consensus_beefy as beefy;
+use sp_mmr_primitives as mmr;
 
 pub use frame_system::Call as SystemCall;
 pub use pallet_balances::Call as BalancesCall;
@@ -30,13 +32,20 @@ pub use sp_runtime::{Perbill, Perquintill};
 pub use pallet_halom_oracle;
 pub use pallet_pow_rewards;
 
+mod migrations;
+
 impl_opaque_keys! {
     pub struct SessionKeys {
         pub aura: Aura,
         pub grandpa: Grandpa,
+        pub beefy: Beefy,
+        pub halom_oracle: HalomOracleId,
     }
 }
 
+/// Opaque session-key wrapper for the Halom oracle's offchain-worker signing key.
+pub type HalomOracleId = pallet_halom_oracle::crypto::Public;
+
 #[sp_version::runtime_version]
 pub const VERSION: RuntimeVersion = RuntimeVersion {
     spec_name: create_runtime_str!("halom-node"),
@@ -128,67 +137,301 @@ impl frame_system::Config for Runtime {
 
 parameter_types! {
     pub const OracleUpdateInterval: BlockNumber = DAYS;  // Update HOI daily
-    pub const BaseReward: Balance = 1_000_000_000;  // 1 HOM
-    pub const MaxSupply: Balance = 21_000_000_000_000_000;  // 21M HOM
-    
-    // License prices
-    pub const StandardLicensePrice: Balance = 1_000_000_000_000;    // 1,000 HOM
-    pub const PremiumLicensePrice: Balance = 5_000_000_000_000;     // 5,000 HOM
-    pub const EnterpriseLicensePrice: Balance = 20_000_000_000_000; // 20,000 HOM
-    
-    // License duration (30 days)
-    pub const LicenseDuration: BlockNumber = 2_628_000;  // ~1 év (6 másodperces blokkidővel)
-    
+
+    // NPoS-style reward curve: per-block base reward derived from the current
+    // staking ratio, spread over BlocksPerYear blocks.
+    pub const BlocksPerYear: u32 = 5_256_000;  // ~6 second block time
+
     // Treasury
     pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
-    pub const TreasuryFeePercent: Permill = Permill::from_percent(15);  // 15% treasury fee
 
     // Oracle parameters
-    pub const MinUpdateInterval: BlockNumber = DAYS;  // Minimum 1 day
-    pub const MaxUpdateInterval: BlockNumber = 7 * DAYS;  // Maximum 7 days
     pub const MinSourcesForConsensus: u32 = 2;  // At least 2 sources needed
     
     // Governance parameters
     pub const OracleCouncilMembers: u32 = 3;
     pub const OracleMotionDuration: BlockNumber = 3 * DAYS;
     pub const OracleMaxProposals: u32 = 100;
+
+    // Miner misbehavior slashing
+    pub const SlashFraction: Permill = Permill::from_percent(10);
+    pub const MaxStrikes: u32 = 3;
+
+    // Reward vesting
+    pub const ImmediateRewardPayout: bool = false;
+    pub const VestingDuration: BlockNumber = 30 * DAYS;
+
+    // HOI sanitization bounds used by the reward curve's oracle read
+    pub const MinHOI: u32 = 1;
+    pub const MaxHOI: u32 = 1_000_000;
+    pub const MaxHOIVariation: Permill = Permill::from_percent(20);
+
+    // License fee split
+    pub const LicenseFeeTreasuryShare: Permill = Permill::from_percent(50);
+}
+
+/// Concrete NPoS-style inflation curve parameters for `pallet_pow_rewards`:
+/// inflation rises from 2.5% to 20% as the staking ratio climbs to 50%, then
+/// decays back toward 2.5% past that point with a 5% falloff.
+pub struct RewardCurveParameters;
+impl pallet_pow_rewards::RewardCurve for RewardCurveParameters {
+    fn min_inflation() -> Permill {
+        Permill::from_parts(25_000)
+    }
+    fn ideal_inflation() -> Permill {
+        Permill::from_percent(20)
+    }
+    fn ideal_stake() -> Permill {
+        Permill::from_percent(50)
+    }
+    fn falloff() -> Permill {
+        Permill::from_percent(5)
+    }
+}
+
+/// Governance-tunable economic and oracle-timing knobs, backed by on-chain storage
+/// instead of compile-time constants so the council can retune them without a
+/// runtime upgrade. Each leaf is exposed through the same `Get` interface the
+/// pallets already consume.
+#[frame_support::dynamic_params::dynamic_params(RuntimeParameters, pallet_parameters::Parameters::<Runtime>)]
+pub mod dynamic_params {
+    use super::*;
+
+    #[frame_support::dynamic_params::dynamic_pallet_params]
+    #[codec(index = 0)]
+    pub mod rewards {
+        /// Fee charged on each license purchase, as a fraction of the license price.
+        #[codec(index = 0)]
+        pub static TreasuryFeePercent: Permill = Permill::from_percent(15);
+
+        /// Blocks a purchased license stays active for.
+        #[codec(index = 1)]
+        pub static LicenseDuration: BlockNumber = 2_628_000;
+    }
+
+    #[frame_support::dynamic_params::dynamic_pallet_params]
+    #[codec(index = 1)]
+    pub mod oracle {
+        /// Minimum blocks between accepted HOI updates.
+        #[codec(index = 0)]
+        pub static MinUpdateInterval: BlockNumber = DAYS;
+
+        /// Maximum blocks between accepted HOI updates.
+        #[codec(index = 1)]
+        pub static MaxUpdateInterval: BlockNumber = 7 * DAYS;
+
+        /// Majority percentage required to enact an oracle parameter proposal.
+        #[codec(index = 2)]
+        pub static RequiredMajority: u32 = 66;
+    }
 }
 
-// Oracle Council implementation
-pub struct OracleCouncil;
-impl halom_oracle::IsCouncilMember<AccountId> for OracleCouncil {
-    fn is_council_member(who: &AccountId) -> bool {
-        // Initially use pallet_collective members
-        pallet_collective::Pallet::<Runtime, pallet_collective::DefaultInstance>
-            ::is_member(who)
+pub use dynamic_params::{RuntimeParameters, RuntimeParametersKey};
+
+/// Routes writes to dynamic parameters: monetary knobs require root, oracle-timing
+/// knobs require an `OracleCouncil` majority.
+pub struct DynamicParameterOrigin;
+
+impl frame_support::traits::EnsureOriginWithArg<RuntimeOrigin, RuntimeParametersKey>
+    for DynamicParameterOrigin
+{
+    type Success = ();
+
+    fn try_origin(
+        origin: RuntimeOrigin,
+        key: &RuntimeParametersKey,
+    ) -> Result<Self::Success, RuntimeOrigin> {
+        match key {
+            RuntimeParametersKey::Rewards(_) => {
+                EnsureRoot::<AccountId>::try_origin(origin).map(|_| ())
+            }
+            RuntimeParametersKey::Oracle(_) => {
+                pallet_collective::EnsureProportionAtLeast::<AccountId, pallet_collective::DefaultInstance, 2, 3>::try_origin(origin).map(|_| ())
+            }
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin(_key: &RuntimeParametersKey) -> Result<RuntimeOrigin, ()> {
+        Ok(RuntimeOrigin::root())
+    }
+}
+
+impl pallet_parameters::Config for Runtime {
+    type RuntimeParameters = RuntimeParameters;
+    type RuntimeEvent = RuntimeEvent;
+    type AdminOrigin = DynamicParameterOrigin;
+    type WeightInfo = ();
+}
+
+parameter_types! {
+    pub const MmrLeafVersion: pallet_mmr::primitives::LeafVersion = 0;
+    pub const BeefyMaxAuthorities: u32 = 100;
+    pub const BeefyMaxNominators: u32 = 0;
+    pub const BeefyMaxSetIdSessionEntries: u64 = 56;
+}
+
+/// Embeds the latest committed HOI (and the block it was set at) into the MMR
+/// leaf's extra data, so a relayer holding a BEEFY-signed commitment and an MMR
+/// proof can convince a remote chain of a specific historical HOI value.
+pub struct HoiMmrDataProvider;
+
+impl pallet_beefy_mmr::BeefyDataProvider<(u32, BlockNumber)> for HoiMmrDataProvider {
+    fn extra_data() -> (u32, BlockNumber) {
+        (
+            pallet_halom_oracle::CurrentHOI::<Runtime>::get(),
+            pallet_halom_oracle::LastUpdate::<Runtime>::get(),
+        )
     }
 }
 
+impl pallet_mmr::Config for Runtime {
+    const INDEXING_PREFIX: &'static [u8] = b"mmr";
+    type Hashing = BlakeTwo256;
+    type LeafData = pallet_beefy_mmr::Pallet<Runtime>;
+    type OnNewRoot = pallet_beefy_mmr::DepositBeefyDigest<Runtime>;
+    type WeightInfo = ();
+}
+
+impl pallet_beefy::Config for Runtime {
+    type BeefyId = beefy::ecdsa_crypto::AuthorityId;
+    type MaxAuthorities = BeefyMaxAuthorities;
+    type MaxNominators = BeefyMaxNominators;
+    type MaxSetIdSessionEntries = BeefyMaxSetIdSessionEntries;
+    type OnNewValidatorSet = BeefyMmrLeaf;
+    type WeightInfo = ();
+    type KeyOwnerProof = sp_core::Void;
+    type EquivocationReportSystem = ();
+}
+
+impl pallet_beefy_mmr::Config for Runtime {
+    type LeafVersion = MmrLeafVersion;
+    type BeefyAuthorityToMerkleLeaf = pallet_beefy_mmr::BeefyEcdsaToEthereum;
+    type LeafExtra = (u32, BlockNumber);
+    type BeefyDataProvider = HoiMmrDataProvider;
+}
+
 impl halom_oracle::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type OracleUpdateOrigin = EnsureRoot<AccountId>;
     type GovernanceOrigin = EnsureRoot<AccountId>;
-    type CouncilMembers = OracleCouncil;
+    type MembershipOrigin = EnsureRoot<AccountId>;
     type VotingPeriod = ConstU32<1000>; // ~2 óra
-    type MinUpdateInterval = ConstU32<100>; // ~20 perc
-    type MaxUpdateInterval = ConstU32<2400>; // ~8 óra
+    type MinUpdateInterval = dynamic_params::oracle::MinUpdateInterval;
+    type MaxUpdateInterval = dynamic_params::oracle::MaxUpdateInterval;
     type MinSourcesForConsensus = ConstU32<2>;
-    type RequiredMajority = ConstU32<66>; // 66% többség szükséges
+    type RequiredMajority = dynamic_params::oracle::RequiredMajority;
+    type AuthorityId = pallet_halom_oracle::crypto::OracleAuthId;
+    type Aggregator = pallet_halom_oracle::MedianAbsoluteDeviation<OutlierDeviationFactor>;
+    type OutlierDeviationFactor = OutlierDeviationFactor;
+    type MaxObservationAge = ConstU32<600>; // ~1 óra
+    type ObservationConsensusEnabled = ObservationConsensusEnabled;
+    type Currency = Balances;
+    type CouncilBond = CouncilBond;
+    type LivenessPenalty = CouncilLivenessPenalty;
+    type MaxMissedHeartbeats = MaxMissedHeartbeats;
+    type ConvictionVoteLockPeriod = ConvictionVoteLockPeriod;
+    type MaxAgendaItemsPerBlock = MaxAgendaItemsPerBlock;
+    type RuntimeCall = RuntimeCall;
+    type MaxProposalLen = MaxProposalLen;
+    type MaxProposalWeight = MaxProposalWeight;
+    type MinQuorum = MinQuorum;
+    type MaxReputation = MaxReputation;
+    type MinReputation = MinReputation;
+    type ReputationStepSize = ReputationStepSize;
+    type ReputationTolerance = ReputationTolerance;
+    type StalenessWindow = StalenessWindow;
+    type MaxCouncilMembers = MaxCouncilMembers;
+    type MinVotingDuration = MinVotingDuration;
+    type WeightInfo = pallet_halom_oracle::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    pub const OutlierDeviationFactor: u32 = 3; // k = 3
+    pub const CouncilBond: Balance = 10_000_000_000_000; // 10,000 HOM
+    pub const CouncilLivenessPenalty: Permill = Permill::from_percent(10);
+    pub const MaxMissedHeartbeats: u32 = 3;
+    pub const ConvictionVoteLockPeriod: BlockNumber = 7 * DAYS;
+    pub const MaxAgendaItemsPerBlock: u32 = 20;
+    pub const MaxProposalLen: u32 = 4096;
+    pub const MaxProposalWeight: Weight = Weight::from_parts(1_000_000_000, 0);
+    pub const MinQuorum: Permill = Permill::from_percent(50);
+    pub const MaxReputation: u32 = 200;
+    pub const MinReputation: u32 = 10;
+    pub const ReputationStepSize: u32 = 5;
+    pub const ReputationTolerance: Permill = Permill::from_percent(2);
+    pub const StalenessWindow: BlockNumber = 600; // ~1 óra
+    pub const MaxCouncilMembers: u32 = 100;
+    pub const MinVotingDuration: BlockNumber = 100; // ~10 perc
+    // submit_source_value/submit_signed_source_value (try_consensus) is this
+    // runtime's canonical oracle consensus path; submit_observation is kept
+    // disabled so the two can't race to commit CurrentHOI in the same block.
+    pub const ObservationConsensusEnabled: bool = false;
+}
+
+impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Runtime
+where
+    RuntimeCall: From<LocalCall>,
+{
+    fn create_transaction<C: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>>(
+        call: RuntimeCall,
+        public: <Signature as sp_runtime::traits::Verify>::Signer,
+        account: AccountId,
+        nonce: Index,
+    ) -> Option<(RuntimeCall, <UncheckedExtrinsic as sp_runtime::traits::Extrinsic>::SignaturePayload)> {
+        let tip = 0;
+        let extra = SignedExtra::from((
+            frame_system::CheckNonZeroSender::<Runtime>::new(),
+            frame_system::CheckSpecVersion::<Runtime>::new(),
+            frame_system::CheckTxVersion::<Runtime>::new(),
+            frame_system::CheckGenesis::<Runtime>::new(),
+            frame_system::CheckEra::<Runtime>::from(generic::Era::Immortal),
+            frame_system::CheckNonce::<Runtime>::from(nonce),
+            frame_system::CheckWeight::<Runtime>::new(),
+            pallet_transaction_payment::ChargeTransactionPayment::<Runtime>::from(tip),
+        ));
+        let raw_payload = generic::SignedPayload::new(call, extra).ok()?;
+        let signature = raw_payload.using_encoded(|payload| C::sign(payload, public))?;
+        let (call, extra, _) = raw_payload.deconstruct();
+        let address = sp_runtime::MultiAddress::Id(account);
+        Some((call, (address, signature, extra)))
+    }
+}
+
+impl frame_system::offchain::SigningTypes for Runtime {
+    type Public = <Signature as sp_runtime::traits::Verify>::Signer;
+    type Signature = Signature;
+}
+
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Runtime
+where
+    RuntimeCall: From<C>,
+{
+    type OverarchingCall = RuntimeCall;
+    type Extrinsic = UncheckedExtrinsic;
 }
 
 impl pallet_pow_rewards::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
-    type BaseReward = BaseReward;
-    type MaxSupply = MaxSupply;
-    type StandardLicensePrice = StandardLicensePrice;
-    type PremiumLicensePrice = PremiumLicensePrice;
-    type EnterpriseLicensePrice = EnterpriseLicensePrice;
-    type LicenseDuration = LicenseDuration;
+    type RewardCurveParameters = RewardCurveParameters;
+    type BlocksPerYear = BlocksPerYear;
+    type LicenseDuration = dynamic_params::rewards::LicenseDuration;
     type TreasuryPalletId = TreasuryPalletId;
-    type TreasuryFeePercent = TreasuryFeePercent;
+    type TreasuryFeePercent = dynamic_params::rewards::TreasuryFeePercent;
     type MinimumStake = MinimumStake;
     type StakingBonus = StakingBonus;
+    type SlashOrigin = EnsureRoot<AccountId>;
+    type GovernanceOrigin = EnsureRoot<AccountId>;
+    type SlashFraction = SlashFraction;
+    type MaxStrikes = MaxStrikes;
+    type ImmediateRewardPayout = ImmediateRewardPayout;
+    type VestingDuration = VestingDuration;
+    type MinHOI = MinHOI;
+    type MaxHOI = MaxHOI;
+    type MaxHOIVariation = MaxHOIVariation;
+    type OnLicenseFee = pallet_pow_rewards::SplitToTreasuryAndAuthor<Runtime>;
+    type LicenseFeeTreasuryShare = LicenseFeeTreasuryShare;
 }
 
 parameter_types! {
@@ -228,9 +471,24 @@ construct_runtime!(
         HalomOracle: pallet_halom_oracle::{Pallet, Call, Storage, Event<T>},
         PowRewards: pallet_pow_rewards,
         OracleCouncil: pallet_collective::<Instance1>::{Pallet, Call, Storage, Origin<T>, Event<T>, Config<T>},
+        Parameters: pallet_parameters,
+        Mmr: pallet_mmr,
+        Beefy: pallet_beefy,
+        BeefyMmrLeaf: pallet_beefy_mmr,
     }
 );
 
+/// Storage migrations applied in order on a runtime upgrade. See `migrations` for
+/// the per-pallet `VersionedMigration`s.
+pub type Executive = frame_executive::Executive<
+    Runtime,
+    Block,
+    frame_system::ChainContext<Runtime>,
+    Runtime,
+    AllPalletsWithSystem,
+    migrations::Migrations,
+>;
+
 #[cfg(feature = "runtime-benchmarks")]
 #[macro_use]
 extern crate frame_benchmarking;
@@ -241,6 +499,7 @@ mod benches {
         [frame_system, SystemBench::<Runtime>]
         [pallet_balances, Balances]
         [pallet_timestamp, Timestamp]
+        [pallet_halom_oracle, HalomOracle]
     );
 }
 
@@ -295,6 +554,73 @@ impl_runtime_apis! {
             Executive::validate_transaction(source, tx, block_hash)
         }
     }
+
+    impl beefy::BeefyApi<Block, beefy::ecdsa_crypto::AuthorityId> for Runtime {
+        fn beefy_genesis() -> Option<BlockNumber> {
+            Beefy::genesis_block()
+        }
+
+        fn validator_set() -> Option<beefy::ValidatorSet<beefy::ecdsa_crypto::AuthorityId>> {
+            Beefy::validator_set()
+        }
+
+        fn submit_report_equivocation_unsigned_extrinsic(
+            _equivocation_proof: beefy::EquivocationProof<
+                BlockNumber,
+                beefy::ecdsa_crypto::AuthorityId,
+                beefy::ecdsa_crypto::Signature,
+            >,
+            _key_owner_proof: beefy::OpaqueKeyOwnershipProof,
+        ) -> Option<()> {
+            None
+        }
+
+        fn generate_key_ownership_proof(
+            _set_id: beefy::ValidatorSetId,
+            _authority_id: beefy::ecdsa_crypto::AuthorityId,
+        ) -> Option<beefy::OpaqueKeyOwnershipProof> {
+            None
+        }
+    }
+
+    impl mmr::MmrApi<Block, mmr::Hash, BlockNumber> for Runtime {
+        fn mmr_root() -> Result<mmr::Hash, mmr::Error> {
+            Mmr::mmr_root().ok_or(mmr::Error::Push)
+        }
+
+        fn mmr_leaf_count() -> Result<mmr::LeafIndex, mmr::Error> {
+            Mmr::mmr_leaves()
+        }
+
+        fn generate_proof(
+            block_numbers: Vec<BlockNumber>,
+            best_known_block_number: Option<BlockNumber>,
+        ) -> Result<(Vec<mmr::EncodableOpaqueLeaf>, mmr::Proof<mmr::Hash>), mmr::Error> {
+            Mmr::generate_proof(block_numbers, best_known_block_number).map(|(leaves, proof)| {
+                (
+                    leaves.into_iter().map(|leaf| mmr::EncodableOpaqueLeaf::from_leaf(&leaf)).collect(),
+                    proof,
+                )
+            })
+        }
+
+        fn verify_proof(leaves: Vec<mmr::EncodableOpaqueLeaf>, proof: mmr::Proof<mmr::Hash>) -> Result<(), mmr::Error> {
+            let leaves = leaves
+                .into_iter()
+                .map(|leaf| leaf.into_opaque_leaf().try_decode().ok_or(mmr::Error::Verify))
+                .collect::<Result<Vec<_>, mmr::Error>>()?;
+            Mmr::verify_leaves(leaves, proof)
+        }
+
+        fn verify_proof_stateless(
+            root: mmr::Hash,
+            leaves: Vec<mmr::EncodableOpaqueLeaf>,
+            proof: mmr::Proof<mmr::Hash>,
+        ) -> Result<(), mmr::Error> {
+            let nodes = leaves.into_iter().map(|leaf| mmr::DataOrHash::Data(leaf.into_opaque_leaf())).collect();
+            pallet_mmr::verify_leaves_proof::<mmr::Hashing, _>(root, nodes, proof)
+        }
+    }
 }
 
 impl pallet_collective::Config<OracleCouncilInstance> for Runtime {